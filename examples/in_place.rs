@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy_color::palettes::css::{BLUE, ORANGE, WHITE};
+use bevy_nested_tooltips::prelude::*;
+use bevy_platform::collections::HashMap;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, NestedTooltipPlugin))
+        .add_systems(Startup, spawn_scene)
+        .add_systems(Update, (navigate_history, show_breadcrumb))
+        .add_observer(style_tooltip)
+        .run()
+}
+
+#[derive(Component)]
+struct Breadcrumb;
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Links inside a tooltip replace its content instead of opening another tooltip
+    commands.insert_resource(TooltipConfiguration {
+        nesting: NestingMode::InPlace,
+        ..Default::default()
+    });
+
+    commands.spawn((
+        Node {
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        },
+        children![
+            (
+                Text::new("Hover the "),
+                children![
+                    (
+                        TextSpan::new("glossary"),
+                        TooltipTermLink::new("glossary"),
+                        TextColor(BLUE.into())
+                    ),
+                    TextSpan::new(", press left and right arrows to go back and forward."),
+                ]
+            ),
+            (Text::new(""), Breadcrumb)
+        ],
+    ));
+
+    let mut tooltip_map = TooltipMap {
+        map: HashMap::new(),
+    };
+
+    tooltip_map.insert(
+        "glossary".into(),
+        TooltipsData::new(
+            "Glossary",
+            vec![
+                TooltipsContent::String("Read about ".into()),
+                TooltipsContent::Term("mana".into()),
+                TooltipsContent::String(" or ".into()),
+                TooltipsContent::Term("spells".into()),
+                TooltipsContent::String(".".into()),
+            ],
+        ),
+    );
+
+    tooltip_map.insert(
+        "mana".into(),
+        TooltipsData::new(
+            "Mana",
+            vec![
+                TooltipsContent::String("Spent to cast ".into()),
+                TooltipsContent::Term("spells".into()),
+                TooltipsContent::String(".".into()),
+            ],
+        ),
+    );
+
+    tooltip_map.insert(
+        "spells".into(),
+        TooltipsData::new(
+            "Spells",
+            vec![
+                TooltipsContent::String("Cost ".into()),
+                TooltipsContent::Term("mana".into()),
+                TooltipsContent::String(" to cast.".into()),
+            ],
+        ),
+    );
+
+    commands.insert_resource(tooltip_map);
+}
+
+fn navigate_history(
+    keycode: Res<ButtonInput<KeyCode>>,
+    tooltip_query: Query<Entity, With<TooltipHistory>>,
+    mut commands: Commands,
+) {
+    for tooltip in &tooltip_query {
+        if keycode.just_pressed(KeyCode::ArrowLeft) {
+            commands.navigate_back(tooltip);
+        }
+        if keycode.just_pressed(KeyCode::ArrowRight) {
+            commands.navigate_forward(tooltip);
+        }
+    }
+}
+
+fn show_breadcrumb(
+    history_query: Query<&TooltipHistory, Changed<TooltipHistory>>,
+    mut breadcrumb_query: Query<&mut Text, With<Breadcrumb>>,
+) {
+    for history in &history_query {
+        for mut text in &mut breadcrumb_query {
+            text.0 = history.breadcrumb().join(" > ");
+        }
+    }
+}
+
+fn style_tooltip(tooltip: On<Add, Tooltip>, mut commands: Commands) {
+    commands
+        .get_entity(tooltip.entity)
+        .unwrap()
+        .insert((BackgroundColor(ORANGE.into()), BorderColor::all(WHITE)));
+}
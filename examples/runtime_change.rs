@@ -4,7 +4,6 @@ use bevy_color::palettes::css::{BLUE, GREEN, ORANGE, ORANGE_RED, WHITE, YELLOW_G
 use bevy_nested_tooltips::prelude::*;
 use bevy_platform::collections::HashMap;
 use bevy_ui::RelativeCursorPosition;
-use bevy_window::WindowMode;
 
 #[derive(Component)]
 struct LockMessage;
@@ -14,17 +13,7 @@ struct ConfigActivationText;
 
 fn main() -> AppExit {
     App::new()
-        .add_plugins((
-            //This library only works for fullscreen
-            DefaultPlugins.set(WindowPlugin {
-                primary_window: Some(Window {
-                    mode: WindowMode::BorderlessFullscreen(MonitorSelection::Current),
-                    ..Default::default()
-                }),
-                ..Default::default()
-            }),
-            NestedTooltipPlugin,
-        ))
+        .add_plugins((DefaultPlugins, NestedTooltipPlugin))
         // .add_plugins(EguiPlugin::default())
         // .add_plugins(WorldInspectorPlugin::new())
         .add_systems(Startup, spawn_scene)
@@ -329,12 +318,14 @@ fn update_settings(
     if keycode.just_pressed(KeyCode::KeyA) {
         for mut text in &mut activation_text_query {
             match activation {
-                ActivationMethod::MiddleMouse => {
+                ActivationMethod::Press { .. } => {
                     text.0 = "I am currently on hover".to_string();
                     config.activation_method = ActivationMethod::default();
                 }
-                ActivationMethod::Hover { .. } => {
-                    config.activation_method = ActivationMethod::MiddleMouse;
+                _ => {
+                    config.activation_method = ActivationMethod::Press {
+                        button: PointerButton::Middle,
+                    };
                     text.0 = "I am currently on middle mouse".to_string()
                 }
             }
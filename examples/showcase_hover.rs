@@ -4,24 +4,13 @@ use bevy_color::palettes::css::{BLUE, GREEN, ORANGE, ORANGE_RED, WHITE, YELLOW_G
 use bevy_nested_tooltips::prelude::*;
 use bevy_platform::collections::HashMap;
 use bevy_ui::RelativeCursorPosition;
-use bevy_window::WindowMode;
 
 #[derive(Component)]
 struct LockMessage;
 
 fn main() -> AppExit {
     App::new()
-        .add_plugins((
-            //This library only works for fullscreen
-            DefaultPlugins.set(WindowPlugin {
-                primary_window: Some(Window {
-                    mode: WindowMode::BorderlessFullscreen(MonitorSelection::Current),
-                    ..Default::default()
-                }),
-                ..Default::default()
-            }),
-            NestedTooltipPlugin,
-        ))
+        .add_plugins((DefaultPlugins, NestedTooltipPlugin))
         // .add_plugins(EguiPlugin::default())
         // .add_plugins(WorldInspectorPlugin::new())
         .add_systems(Startup, spawn_scene)
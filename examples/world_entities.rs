@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use bevy_color::palettes::css::{BLUE, GREEN, ORANGE, WHITE};
+use bevy_nested_tooltips::prelude::*;
+use bevy_platform::collections::HashMap;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, NestedTooltipPlugin))
+        .add_systems(Startup, spawn_scene)
+        .add_systems(Update, patrol)
+        .add_observer(style_tooltip)
+        .run()
+}
+
+#[derive(Component)]
+struct Patrol;
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Tooltips follow the entity that opened them as it moves
+    commands.insert_resource(TooltipConfiguration {
+        placement: TooltipPlacement::Tracking,
+        ..Default::default()
+    });
+
+    // Any pickable entity can link to a tooltip, it opens where the entity is on screen
+    commands.spawn((
+        Sprite::from_color(BLUE, Vec2::splat(80.)),
+        Transform::from_xyz(-150., 0., 0.),
+        TooltipTermLink::new("barracks"),
+    ));
+    commands.spawn((
+        Sprite::from_color(GREEN, Vec2::splat(40.)),
+        Transform::from_xyz(150., 0., 0.),
+        TooltipTermLink::new("soldier"),
+        Patrol,
+    ));
+
+    let mut tooltip_map = TooltipMap {
+        map: HashMap::new(),
+    };
+
+    tooltip_map.insert(
+        "barracks".into(),
+        TooltipsData::new(
+            "Barracks",
+            vec![
+                TooltipsContent::String("Trains ".into()),
+                TooltipsContent::Term("soldier".into()),
+                TooltipsContent::String("s for your army.".into()),
+            ],
+        ),
+    );
+
+    tooltip_map.insert(
+        "soldier".into(),
+        TooltipsData::new(
+            "Soldier",
+            vec![TooltipsContent::String(
+                "A basic unit trained at the barracks.".into(),
+            )],
+        ),
+    );
+
+    commands.insert_resource(tooltip_map);
+}
+
+fn patrol(mut patrol_query: Query<&mut Transform, With<Patrol>>, time: Res<Time>) {
+    for mut transform in &mut patrol_query {
+        transform.translation.y = time.elapsed_secs().sin() * 200.;
+    }
+}
+
+fn style_tooltip(tooltip: On<Add, Tooltip>, mut commands: Commands) {
+    commands
+        .get_entity(tooltip.entity)
+        .unwrap()
+        .insert((BackgroundColor(ORANGE.into()), BorderColor::all(WHITE)));
+}
@@ -0,0 +1,229 @@
+//! Submenu style aiming, enabled with [`crate::TooltipConfiguration::safe_triangle`].
+//! While the pointer travels from a link towards the tooltip it opened, inside the triangle between
+//! where it last was over the link and the near edge of the tooltip, links the pointer crosses do not
+//! open tooltips and the tooltip does not time out.
+//! Aiming ends once the pointer rests for
+//! [`crate::TooltipConfiguration::aim_timeout`], so a resting pointer can not hold tooltips open.
+
+use std::time::Duration;
+
+use bevy_app::{Plugin, Update};
+use bevy_camera::Camera;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::On,
+    query::{Has, QueryData},
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res},
+};
+use bevy_math::{Rect, Vec2};
+use bevy_picking::{
+    events::{Move, Pointer},
+    pointer::{PointerId, PointerLocation},
+};
+use bevy_time::Time;
+use bevy_ui::{UiScale, UiTargetCamera};
+use tiny_bail::prelude::*;
+
+use crate::{
+    ToolTipDebounced, Tooltip, TooltipConfiguration, placement::LinkRectParam, tick_timers,
+    ui_space_position,
+};
+
+pub(crate) struct AimPlugin;
+
+impl Plugin for AimPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(Update, update_aiming.before(tick_timers))
+            .add_observer(record_aim);
+    }
+}
+
+/// Where the pointer last was over the link of a [`Tooltip`], in logical UI space.
+#[derive(Debug, Component)]
+pub(crate) struct TooltipAim {
+    pointer_id: PointerId,
+    apex: Vec2,
+    /// Where the pointer was when it last moved after leaving the link.
+    pointer: Option<Vec2>,
+    /// Elapsed time when the pointer last moved.
+    moved_at: Duration,
+}
+
+/// The pointer is heading from the link towards this [`Tooltip`].
+#[derive(Debug, Component)]
+pub(crate) struct TooltipAiming {
+    /// The pointer inside the safe triangle, links it is over do not open tooltips.
+    pub(crate) pointer_id: PointerId,
+}
+
+/// Records where the pointer is while it moves over the link of a tooltip it has not reached yet.
+fn record_aim(
+    hover: On<Pointer<Move>>,
+    tooltip_query: Query<(Entity, &Tooltip, &UiTargetCamera, Has<ToolTipDebounced>)>,
+    camera_query: Query<&Camera>,
+    ui_scale: Res<UiScale>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    if !tooltip_configuration.safe_triangle {
+        return;
+    }
+    for (entity, tooltip, target_camera, debounced) in tooltip_query {
+        if tooltip.link() != Some(hover.entity) || debounced {
+            continue;
+        }
+        let camera = c!(camera_query.get(target_camera.entity()));
+        let (apex, _) = c!(ui_space_position(
+            &hover.pointer_location,
+            camera,
+            &ui_scale
+        ));
+        c!(commands.get_entity(entity)).insert(TooltipAim {
+            pointer_id: hover.pointer_id,
+            apex,
+            pointer: None,
+            moved_at: time.elapsed(),
+        });
+    }
+}
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct AimQuery {
+    entity: Entity,
+    aim: &'static mut TooltipAim,
+    target_camera: &'static UiTargetCamera,
+    debounced: Has<ToolTipDebounced>,
+    aiming: Has<TooltipAiming>,
+}
+
+/// Marks tooltips with [`TooltipAiming`] while their pointer is moving inside the safe triangle.
+#[allow(clippy::too_many_arguments)]
+fn update_aiming(
+    mut tooltip_query: Query<AimQuery>,
+    pointer_query: Query<(&PointerId, &PointerLocation)>,
+    camera_query: Query<&Camera>,
+    ui_scale: Res<UiScale>,
+    link_rect: LinkRectParam,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for mut tooltip_item in &mut tooltip_query {
+        let pointer = pointer_query
+            .iter()
+            .find(|(id, _)| **id == tooltip_item.aim.pointer_id)
+            .and_then(|(_, pointer_location)| pointer_location.location())
+            .zip(camera_query.get(tooltip_item.target_camera.entity()).ok())
+            .and_then(|(location, camera)| ui_space_position(location, camera, &ui_scale))
+            .map(|(pointer, _)| pointer);
+        if pointer.is_some() && pointer != tooltip_item.aim.pointer {
+            tooltip_item.aim.pointer = pointer;
+            tooltip_item.aim.moved_at = time.elapsed();
+        }
+        let moving = time.elapsed().saturating_sub(tooltip_item.aim.moved_at)
+            < tooltip_configuration.aim_timeout;
+
+        let in_triangle = !tooltip_item.debounced
+            && moving
+            && pointer
+                .zip(link_rect.logical_rect(tooltip_item.entity))
+                .is_some_and(|(pointer, rect)| {
+                    in_safe_triangle(tooltip_item.aim.apex, rect, pointer)
+                });
+
+        if in_triangle && !tooltip_item.aiming {
+            c!(commands.get_entity(tooltip_item.entity)).insert(TooltipAiming {
+                pointer_id: tooltip_item.aim.pointer_id,
+            });
+        } else if !in_triangle && tooltip_item.aiming {
+            c!(commands.get_entity(tooltip_item.entity)).remove::<TooltipAiming>();
+        }
+    }
+}
+
+/// Whether `point` is inside the triangle from `apex` to the edge of `rect` facing it.
+fn in_safe_triangle(apex: Vec2, rect: Rect, point: Vec2) -> bool {
+    if rect.contains(apex) {
+        return false;
+    }
+    let distance_x = (rect.min.x - apex.x).max(apex.x - rect.max.x);
+    let distance_y = (rect.min.y - apex.y).max(apex.y - rect.max.y);
+    let (a, b) = if distance_x >= distance_y {
+        let x = if apex.x < rect.min.x {
+            rect.min.x
+        } else {
+            rect.max.x
+        };
+        (Vec2::new(x, rect.min.y), Vec2::new(x, rect.max.y))
+    } else {
+        let y = if apex.y < rect.min.y {
+            rect.min.y
+        } else {
+            rect.max.y
+        };
+        (Vec2::new(rect.min.x, y), Vec2::new(rect.max.x, y))
+    };
+
+    let side = |from: Vec2, to: Vec2| (to - from).perp_dot(point - from);
+    let sides = [side(apex, a), side(a, b), side(b, apex)];
+    sides.iter().all(|side| *side >= 0.0) || sides.iter().all(|side| *side <= 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECT: Rect = Rect {
+        min: Vec2::new(100., 0.),
+        max: Vec2::new(200., 100.),
+    };
+
+    #[test]
+    fn apex_inside_rect() {
+        assert!(!in_safe_triangle(
+            Vec2::new(150., 50.),
+            RECT,
+            Vec2::new(150., 50.)
+        ));
+    }
+
+    #[test]
+    fn facing_left_edge() {
+        let apex = Vec2::new(50., 50.);
+        assert!(in_safe_triangle(apex, RECT, Vec2::new(80., 50.)));
+        assert!(!in_safe_triangle(apex, RECT, Vec2::new(80., 10.)));
+    }
+
+    #[test]
+    fn facing_right_edge() {
+        let apex = Vec2::new(250., 50.);
+        assert!(in_safe_triangle(apex, RECT, Vec2::new(220., 50.)));
+        assert!(!in_safe_triangle(apex, RECT, Vec2::new(220., 95.)));
+    }
+
+    #[test]
+    fn facing_top_edge() {
+        let apex = Vec2::new(150., -50.);
+        assert!(in_safe_triangle(apex, RECT, Vec2::new(150., -20.)));
+        assert!(!in_safe_triangle(apex, RECT, Vec2::new(110., -20.)));
+    }
+
+    #[test]
+    fn facing_bottom_edge() {
+        let apex = Vec2::new(150., 150.);
+        assert!(in_safe_triangle(apex, RECT, Vec2::new(150., 120.)));
+        assert!(!in_safe_triangle(apex, RECT, Vec2::new(190., 120.)));
+    }
+
+    #[test]
+    fn point_on_boundary() {
+        let apex = Vec2::new(50., 50.);
+        assert!(in_safe_triangle(apex, RECT, Vec2::new(75., 25.)));
+        assert!(in_safe_triangle(apex, RECT, Vec2::new(100., 50.)));
+        assert!(in_safe_triangle(apex, RECT, apex));
+    }
+}
@@ -0,0 +1,152 @@
+//! Opening, closing and locking tooltips from code, for tutorials and scripted events.
+//! Tooltips opened this way are the same as ones the user opens, they nest, time out and
+//! trigger the same events.
+
+use bevy_ecs::{
+    entity::Entity,
+    query::With,
+    system::{Commands, In, Query},
+};
+use bevy_math::Vec2;
+use bevy_ui::UiTargetCamera;
+use tiny_bail::prelude::*;
+
+use crate::{
+    Tooltip, TooltipSpawner, TooltipsNested, events::TooltipLocked, lifecycle::CloseTooltip,
+    placement::TooltipPlacement, term::TooltipTermLinkRecursive,
+};
+
+/// Where a [`Tooltip`] opened with [`TooltipCommands::open_tooltip`] is placed.
+#[derive(Debug, Clone)]
+pub enum TooltipOpenAt {
+    /// At a position in the logical UI space of `camera`, placed as [`TooltipPlacement::Cursor`].
+    /// There is no link, [`Tooltip::link`] is none.
+    Position { position: Vec2, camera: Entity },
+    /// Next to an entity as if it were the link that opened the tooltip, such as a node or a sprite.
+    /// Placed using the configured [`crate::TooltipConfiguration::placement`].
+    Anchor { entity: Entity, camera: Entity },
+}
+
+/// Extension to [`Commands`] for driving tooltips from code.
+///
+/// ```rust
+/// commands.open_tooltip(
+///     "tooltip",
+///     TooltipOpenAt::Anchor {
+///         entity: button,
+///         camera,
+///     },
+/// );
+/// ```
+pub trait TooltipCommands {
+    /// Opens the tooltip of `term` in [`crate::TooltipMap`], closing other top level tooltips.
+    fn open_tooltip(&mut self, term: impl ToString, at: TooltipOpenAt);
+
+    /// Opens the tooltip of `term` nested under the `parent` tooltip.
+    /// If `parent` has a link to `term` it is opened from that link, otherwise next to `parent`.
+    fn open_nested_tooltip(&mut self, term: impl ToString, parent: Entity);
+
+    /// Closes the tooltip and the tooltips nested under it, even if they are locked.
+    fn close_tooltip(&mut self, tooltip: Entity);
+
+    /// Closes every tooltip, even if they are locked.
+    fn close_all_tooltips(&mut self);
+
+    /// Adds [`TooltipLocked`] to the tooltip so it is not closed by timing out or the pointer leaving.
+    fn lock_tooltip(&mut self, tooltip: Entity);
+
+    /// Removes [`TooltipLocked`] from the tooltip.
+    fn unlock_tooltip(&mut self, tooltip: Entity);
+
+    /// Shows the previous term in the [`crate::history::TooltipHistory`] of the tooltip.
+    fn navigate_back(&mut self, tooltip: Entity);
+
+    /// Shows the next term in the [`crate::history::TooltipHistory`] of the tooltip.
+    fn navigate_forward(&mut self, tooltip: Entity);
+}
+
+impl TooltipCommands for Commands<'_, '_> {
+    fn open_tooltip(&mut self, term: impl ToString, at: TooltipOpenAt) {
+        self.run_system_cached_with(open_tooltip, (term.to_string(), at));
+    }
+
+    fn open_nested_tooltip(&mut self, term: impl ToString, parent: Entity) {
+        self.run_system_cached_with(open_nested_tooltip, (term.to_string(), parent));
+    }
+
+    fn close_tooltip(&mut self, tooltip: Entity) {
+        self.run_system_cached_with(close_tooltip, tooltip);
+    }
+
+    fn close_all_tooltips(&mut self) {
+        self.run_system_cached(close_all_tooltips);
+    }
+
+    fn lock_tooltip(&mut self, tooltip: Entity) {
+        self.entity(tooltip).try_insert(TooltipLocked);
+    }
+
+    fn unlock_tooltip(&mut self, tooltip: Entity) {
+        self.entity(tooltip).try_remove::<TooltipLocked>();
+    }
+
+    fn navigate_back(&mut self, tooltip: Entity) {
+        self.run_system_cached_with(navigate_back, tooltip);
+    }
+
+    fn navigate_forward(&mut self, tooltip: Entity) {
+        self.run_system_cached_with(navigate_forward, tooltip);
+    }
+}
+
+fn open_tooltip(In((term, at)): In<(String, TooltipOpenAt)>, mut spawner: TooltipSpawner) {
+    match at {
+        TooltipOpenAt::Position { position, camera } => {
+            let tooltip = rq!(spawner.spawn_term(None, &term, None, position, camera));
+            r!(spawner.commands().get_entity(tooltip)).insert(TooltipPlacement::Cursor);
+        }
+        TooltipOpenAt::Anchor { entity, camera } => {
+            spawner.spawn_term_at_anchor(entity, &term, None, camera);
+        }
+    }
+}
+
+fn open_nested_tooltip(
+    In((term, parent)): In<(String, Entity)>,
+    links_query: Query<(Entity, &TooltipTermLinkRecursive)>,
+    camera_query: Query<&UiTargetCamera, With<Tooltip>>,
+    mut spawner: TooltipSpawner,
+) {
+    let camera = r!(camera_query.get(parent)).entity();
+    let link = links_query
+        .iter()
+        .find(|(_, link)| link.parent_entity == parent && link.linked_string == term);
+    match link {
+        Some((link, _)) => spawner.spawn_at_link(link, camera),
+        None => spawner.spawn_term_at_anchor(parent, &term, Some(parent), camera),
+    };
+}
+
+fn close_tooltip(
+    In(tooltip): In<Entity>,
+    nested_query: Query<&TooltipsNested>,
+    mut commands: Commands,
+) {
+    for entity in std::iter::once(tooltip).chain(nested_query.iter_descendants(tooltip)) {
+        commands.trigger(CloseTooltip { entity });
+    }
+}
+
+fn close_all_tooltips(tooltip_query: Query<Entity, With<Tooltip>>, mut commands: Commands) {
+    for entity in tooltip_query {
+        commands.trigger(CloseTooltip { entity });
+    }
+}
+
+fn navigate_back(In(tooltip): In<Entity>, mut spawner: TooltipSpawner) {
+    spawner.navigate_back(tooltip);
+}
+
+fn navigate_forward(In(tooltip): In<Entity>, mut spawner: TooltipSpawner) {
+    spawner.navigate_forward(tooltip);
+}
@@ -1,7 +1,8 @@
 //! The events that are intended to be read by the user to react to are stored here.
 //!
 
-use bevy_ecs::component::Component;
+use bevy_ecs::{component::Component, entity::Entity, event::EntityEvent};
+use bevy_math::Vec2;
 
 /// Marker to indicate this node is currently being highlighted by this tooltip
 /// When this component is added user should apply styling so it's obvious to the player
@@ -15,3 +16,23 @@ pub struct TooltipHighlighting;
 /// that the tooltip will not be despawned by timeout or pointer leaving.
 #[derive(Debug, Component)]
 pub struct TooltipLocked;
+
+/// A locked `ToolTip` that has been dragged by its title, it stays at this position in logical UI space
+/// instead of where it was placed.
+/// Insert this to put a tooltip back where it was previously dragged to.
+#[derive(Debug, Component)]
+pub struct TooltipDragged(pub Vec2);
+
+/// Sent while a `ToolTip` is being dragged with its new position in logical UI space.
+#[derive(Debug, EntityEvent)]
+pub struct TooltipMoved {
+    pub entity: Entity,
+    pub position: Vec2,
+}
+
+/// Sent when a `ToolTip` has been dropped after dragging, with its final position in logical UI space.
+#[derive(Debug, EntityEvent)]
+pub struct TooltipDropped {
+    pub entity: Entity,
+    pub position: Vec2,
+}
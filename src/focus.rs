@@ -0,0 +1,323 @@
+//! Keyboard and gamepad navigation of tooltips using [`InputFocus`].
+//! When a link gains focus its tooltip is opened, the links inside a tooltip are connected in the
+//! [`DirectionalNavigationMap`] so focus can be moved between them with directional input.
+//!
+//! Inside a tooltip east and west move between its links, north backs out to the link that
+//! opened it and south from that link moves into the tooltip.
+//!
+//! The arrow keys and gamepad d-pad move focus unless [`FocusNavigation::move_focus`] is turned
+//! off, focus the first link by setting [`InputFocus`] yourself.
+
+use bevy_app::{Plugin, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    lifecycle::Remove,
+    observer::On,
+    query::{Has, Or, With, Without},
+    schedule::{IntoScheduleConfigs, common_conditions::resource_changed},
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_input::{
+    ButtonInput,
+    gamepad::{Gamepad, GamepadButton},
+    keyboard::KeyCode,
+};
+use bevy_input_focus::{
+    InputFocus,
+    directional_navigation::{DirectionalNavigation, DirectionalNavigationMap},
+};
+use bevy_math::CompassOctant;
+use bevy_ui::ComputedUiTargetCamera;
+use tiny_bail::prelude::*;
+
+use crate::{
+    Tooltip, TooltipConfiguration, TooltipSpawned, TooltipSpawner, TooltipWaitForHover,
+    TooltipsNestedOf,
+    events::TooltipLocked,
+    highlight::TooltipHighlightLink,
+    lifecycle::CloseTooltip,
+    query::TooltipEntitiesParam,
+    term::{TooltipTermLink, TooltipTermLinkRecursive},
+};
+
+pub(crate) struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.init_resource::<InputFocus>()
+            .init_resource::<DirectionalNavigationMap>()
+            .add_systems(
+                Update,
+                (
+                    focus_move,
+                    focus_changed
+                        .run_if(resource_changed::<InputFocus>)
+                        .after(focus_move),
+                    focus_lock,
+                ),
+            )
+            .add_observer(add_navigation)
+            .add_observer(refocus_replaced)
+            .add_observer(remove_term_navigation)
+            .add_observer(remove_highlight_navigation);
+    }
+}
+
+/// Opening tooltips and moving between their links with [`InputFocus`] for when there is no pointer.
+/// Set in [`crate::TooltipConfiguration`].
+#[derive(Debug, Clone)]
+pub struct FocusNavigation {
+    /// Move focus between links with the arrow keys and gamepad d-pad using
+    /// [`DirectionalNavigation`], turn off to move focus with your own input.
+    pub move_focus: bool,
+    /// Key that locks the tooltip of the focused link.
+    pub lock_key: KeyCode,
+    /// Gamepad button that locks the tooltip of the focused link.
+    pub lock_gamepad_button: GamepadButton,
+}
+
+impl Default for FocusNavigation {
+    fn default() -> Self {
+        Self {
+            move_focus: true,
+            lock_key: KeyCode::Space,
+            lock_gamepad_button: GamepadButton::West,
+        }
+    }
+}
+
+/// Marker for a [`crate::Tooltip`] opened by its link gaining focus.
+/// It stays open while focus is on its link or inside it, once focus moves elsewhere it is closed
+/// unless it has been locked.
+#[derive(Debug, Component)]
+pub struct TooltipFocusOpened;
+
+/// Focus was on the content of this [`crate::Tooltip`] when it was replaced.
+#[derive(Debug, Component)]
+pub(crate) struct TooltipRefocus;
+
+/// Links that open a tooltip when focused.
+type FocusLinkFilter = Or<(With<TooltipTermLink>, With<TooltipTermLinkRecursive>)>;
+
+/// Links inside a tooltip that focus can be moved between.
+type NavigableLinkFilter = Or<(With<TooltipTermLinkRecursive>, With<TooltipHighlightLink>)>;
+
+/// Closes focus opened tooltips the focus has left and opens the tooltip of a focused link.
+#[allow(clippy::too_many_arguments)]
+fn focus_changed(
+    input_focus: Res<InputFocus>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    links_query: Query<(), FocusLinkFilter>,
+    tooltip_query: Query<&Tooltip>,
+    focus_opened_query: Query<Entity, (With<TooltipFocusOpened>, Without<TooltipLocked>)>,
+    nested_of_query: Query<&TooltipsNestedOf>,
+    ancestor_query: Query<&ChildOf>,
+    camera_query: Query<&ComputedUiTargetCamera>,
+    mut spawner: TooltipSpawner,
+) {
+    if tooltip_configuration.focus_navigation.is_none() {
+        return;
+    }
+    let focused = input_focus.get();
+
+    // The tooltip the focus is in and the tooltips it is nested under stay open
+    let mut focus_chain = Vec::new();
+    if let Some(focused) = focused {
+        let root = ancestor_query.root_ancestor(focused);
+        if tooltip_query.contains(root) {
+            focus_chain.push(root);
+            focus_chain.extend(nested_of_query.iter_ancestors(root));
+        }
+    }
+    for entity in focus_opened_query {
+        let opened_by_focused = tooltip_query
+            .get(entity)
+            .is_ok_and(|tooltip| focused.is_some() && tooltip.link() == focused);
+        if !opened_by_focused && !focus_chain.contains(&entity) {
+            spawner.commands().trigger(CloseTooltip { entity });
+        }
+    }
+
+    let focused = rq!(focused);
+    if !links_query.contains(focused) {
+        return;
+    }
+    // Text spans are not nodes, the camera is on the text block holding them
+    let camera = std::iter::once(focused)
+        .chain(ancestor_query.iter_ancestors(focused))
+        .find_map(|entity| camera_query.get(entity).ok())
+        .and_then(|camera| camera.get());
+    let camera = rq!(camera);
+    let tooltip = rq!(spawner.spawn_at_link(focused, camera));
+    r!(spawner.commands().get_entity(tooltip))
+        .insert(TooltipFocusOpened)
+        .remove::<TooltipWaitForHover>();
+}
+
+/// Moves focus in the direction of the pressed arrow key or d-pad button.
+fn focus_move(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    gamepad_query: Query<&Gamepad>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut navigation: DirectionalNavigation,
+) {
+    let focus_navigation = rq!(tooltip_configuration.focus_navigation.as_ref());
+    if !focus_navigation.move_focus {
+        return;
+    }
+    let directions = [
+        (
+            KeyCode::ArrowUp,
+            GamepadButton::DPadUp,
+            CompassOctant::North,
+        ),
+        (
+            KeyCode::ArrowRight,
+            GamepadButton::DPadRight,
+            CompassOctant::East,
+        ),
+        (
+            KeyCode::ArrowDown,
+            GamepadButton::DPadDown,
+            CompassOctant::South,
+        ),
+        (
+            KeyCode::ArrowLeft,
+            GamepadButton::DPadLeft,
+            CompassOctant::West,
+        ),
+    ];
+    for (key, button, direction) in directions {
+        let pressed = keyboard
+            .as_ref()
+            .is_some_and(|keyboard| keyboard.just_pressed(key))
+            || gamepad_query
+                .iter()
+                .any(|gamepad| gamepad.just_pressed(button));
+        if pressed {
+            // No neighbour in that direction leaves the focus where it is
+            let _ = navigation.navigate(direction);
+            return;
+        }
+    }
+}
+
+/// When the lock key or gamepad button is pressed add or remove [`TooltipLocked`] on the tooltip
+/// of the focused link, or the tooltip the focus is in.
+fn focus_lock(
+    input_focus: Res<InputFocus>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    gamepad_query: Query<&Gamepad>,
+    tooltip_query: Query<(Entity, &Tooltip, Has<TooltipLocked>)>,
+    ancestor_query: Query<&ChildOf>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut commands: Commands,
+) {
+    let focus_navigation = rq!(tooltip_configuration.focus_navigation.as_ref());
+    let pressed = keyboard.is_some_and(|keyboard| keyboard.just_pressed(focus_navigation.lock_key))
+        || gamepad_query
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(focus_navigation.lock_gamepad_button));
+    if !pressed {
+        return;
+    }
+    let focused = rq!(input_focus.get());
+    let tooltip = tooltip_query
+        .iter()
+        .find(|(_, tooltip, _)| tooltip.link() == Some(focused))
+        .or_else(|| {
+            tooltip_query
+                .get(ancestor_query.root_ancestor(focused))
+                .ok()
+        });
+    let (entity, _, locked) = rq!(tooltip);
+    if locked {
+        r!(commands.get_entity(entity)).remove::<TooltipLocked>();
+    } else {
+        r!(commands.get_entity(entity)).insert(TooltipLocked);
+    }
+}
+
+/// Connects the links of a newly spawned tooltip to each other and to the link that opened it.
+fn add_navigation(
+    spawned: On<TooltipSpawned>,
+    tooltip_query: Query<&Tooltip>,
+    tooltip_entities: TooltipEntitiesParam,
+    children_query: Query<&Children>,
+    links_query: Query<(), NavigableLinkFilter>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut navigation_map: ResMut<DirectionalNavigationMap>,
+) {
+    if tooltip_configuration.focus_navigation.is_none() {
+        return;
+    }
+    let tooltip = r!(tooltip_query.get(spawned.entity));
+    let parts = r!(tooltip_entities.tooltip_child_entities(spawned.entity));
+    let links: Vec<Entity> = children_query
+        .iter_descendants(parts.tooltip_text_node)
+        .filter(|child| links_query.contains(*child))
+        .collect();
+    let first = rq!(links.first().copied());
+
+    if links.len() > 1 {
+        navigation_map.add_looping_edges(&links, CompassOctant::East);
+    }
+    // Tooltips opened from code at a position have no link to back out to
+    let opened_by = rq!(tooltip.link());
+    for link in &links {
+        navigation_map.add_edge(*link, opened_by, CompassOctant::North);
+    }
+    // Links outside of tooltips may already be using south for the users own navigation
+    if links_query.contains(opened_by)
+        || navigation_map
+            .get_neighbor(opened_by, CompassOctant::South)
+            .is_none()
+    {
+        navigation_map.add_edge(opened_by, first, CompassOctant::South);
+    }
+}
+
+/// Moves focus from the replaced content of a tooltip to its first link,
+/// or clears it if the new content has no links.
+fn refocus_replaced(
+    spawned: On<TooltipSpawned>,
+    refocus_query: Query<(), With<TooltipRefocus>>,
+    tooltip_entities: TooltipEntitiesParam,
+    children_query: Query<&Children>,
+    links_query: Query<(), NavigableLinkFilter>,
+    mut input_focus: ResMut<InputFocus>,
+    mut commands: Commands,
+) {
+    if !refocus_query.contains(spawned.entity) {
+        return;
+    }
+    r!(commands.get_entity(spawned.entity)).remove::<TooltipRefocus>();
+    let parts = r!(tooltip_entities.tooltip_child_entities(spawned.entity));
+    let first = children_query
+        .iter_descendants(parts.tooltip_text_node)
+        .find(|child| links_query.contains(*child));
+    match first {
+        Some(first) => input_focus.set(first),
+        None => input_focus.clear(),
+    }
+}
+
+/// Removes links of despawned tooltips from the navigation map.
+fn remove_term_navigation(
+    link: On<Remove, TooltipTermLinkRecursive>,
+    navigation_map: Option<ResMut<DirectionalNavigationMap>>,
+) {
+    let mut navigation_map = rq!(navigation_map);
+    navigation_map.remove(link.entity);
+}
+
+/// Removes links of despawned tooltips from the navigation map.
+fn remove_highlight_navigation(
+    link: On<Remove, TooltipHighlightLink>,
+    navigation_map: Option<ResMut<DirectionalNavigationMap>>,
+) {
+    let mut navigation_map = rq!(navigation_map);
+    navigation_map.remove(link.entity);
+}
@@ -0,0 +1,113 @@
+//! History of the terms shown by a [`crate::Tooltip`].
+//! With [`crate::NestingMode::InPlace`] activating a link inside a tooltip replaces its content
+//! rather than opening a nested tooltip, the visited terms are kept here for back and forward
+//! navigation and breadcrumbs.
+
+use bevy_ecs::component::Component;
+
+/// The terms a [`crate::Tooltip`] has shown, oldest first.
+/// Only tooltips opened with [`crate::NestingMode::InPlace`] have one.
+/// Navigate with [`crate::commands::TooltipCommands::navigate_back`] and
+/// [`crate::commands::TooltipCommands::navigate_forward`].
+#[derive(Debug, Component)]
+pub struct TooltipHistory {
+    terms: Vec<String>,
+    current: usize,
+}
+
+impl TooltipHistory {
+    pub(crate) fn new(term: impl ToString) -> Self {
+        Self {
+            terms: vec![term.to_string()],
+            current: 0,
+        }
+    }
+
+    /// The term currently shown.
+    pub fn current(&self) -> &str {
+        &self.terms[self.current]
+    }
+
+    /// The terms visited to reach the current term, ending with the current term.
+    pub fn breadcrumb(&self) -> &[String] {
+        &self.terms[..=self.current]
+    }
+
+    /// Every term in the history, including those that can be navigated forward to.
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+
+    /// Whether there is a term before the current one.
+    pub fn can_go_back(&self) -> bool {
+        self.current > 0
+    }
+
+    /// Whether there is a term after the current one.
+    pub fn can_go_forward(&self) -> bool {
+        self.current + 1 < self.terms.len()
+    }
+
+    /// Visits a new term, dropping the terms that could be navigated forward to.
+    pub(crate) fn push(&mut self, term: impl ToString) {
+        self.terms.truncate(self.current + 1);
+        self.terms.push(term.to_string());
+        self.current += 1;
+    }
+
+    /// Moves back a term, returning the term now shown.
+    pub(crate) fn back(&mut self) -> Option<&str> {
+        if !self.can_go_back() {
+            return None;
+        }
+        self.current -= 1;
+        Some(self.current())
+    }
+
+    /// Moves forward a term, returning the term now shown.
+    pub(crate) fn forward(&mut self) -> Option<&str> {
+        if !self.can_go_forward() {
+            return None;
+        }
+        self.current += 1;
+        Some(self.current())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_drops_forward_history() {
+        let mut history = TooltipHistory::new("a");
+        history.push("b");
+        history.push("c");
+        assert_eq!(history.back(), Some("b"));
+        history.push("d");
+        assert_eq!(history.terms(), ["a", "b", "d"]);
+        assert_eq!(history.current(), "d");
+        assert!(!history.can_go_forward());
+    }
+
+    #[test]
+    fn back_stops_at_first_term() {
+        let mut history = TooltipHistory::new("a");
+        history.push("b");
+        assert_eq!(history.back(), Some("a"));
+        assert_eq!(history.back(), None);
+        assert_eq!(history.current(), "a");
+        assert_eq!(history.breadcrumb(), ["a"]);
+    }
+
+    #[test]
+    fn forward_stops_at_last_term() {
+        let mut history = TooltipHistory::new("a");
+        history.push("b");
+        assert_eq!(history.forward(), None);
+        history.back();
+        assert_eq!(history.forward(), Some("b"));
+        assert_eq!(history.forward(), None);
+        assert_eq!(history.breadcrumb(), ["a", "b"]);
+    }
+}
@@ -16,6 +16,11 @@ pub struct TooltipTitleText;
 #[derive(Debug, Component)]
 pub struct TooltipTextNode;
 
+/// Marker for the node around the `TooltipTextNode` that scrolls it when the text is too long
+/// to fit in the [`crate::Tooltip`].
+#[derive(Debug, Component)]
+pub(crate) struct TooltipTextScroll;
+
 /// Marker for the [`crate::Tooltip`] texts that is not interactable.
 #[derive(Debug, Component)]
 pub struct TooltipStringText;
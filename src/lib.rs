@@ -3,10 +3,22 @@
 //! ## Features
 //! This library strives to handle the logic behind common tooltip features, while you focus on your unique data and design needs.
 //!
-//! - Tooltips can be spawned by hovering or by user pressing the middle mouse button, your choice which and you can change at runtime.
-//! - Nesting to arbitrary levels, the only limitation is memory.
+//! - Tooltips can be spawned by hovering, long pressing on touch screens or by user pressing a pointer button such as the middle mouse button, your choice which and you can change at runtime.
+//! - Nesting to arbitrary levels, or limited with `max_depth` to stop cyclic terms opening endless tooltips.
+//! - Links can be UI nodes, text or pickable entities in the world such as sprites and meshes.
+//! - Placed next to the cursor, following the cursor or anchored to the link that spawned them, flipping sides when there is no room.
+//! - Nested tooltips cascade beside their parent like submenus, avoiding covering the tooltips they were opened from where there is room.
+//! - Optional wiki style navigation, links replace the content of their tooltip with back and forward history.
+//! - Optional submenu style safe triangle, moving towards a tooltip does not open other links on the way.
 //! - Despawns if the user hasn't interacted with them in a configurable time period, or they mouse away after interacting with them.
-//! - Locking by pressing of the middle mouse button. using observers you can implement your specific design to inform your users.
+//! - Locking by pressing a configurable pointer button, middle mouse by default. using observers you can implement your specific design to inform your users.
+//! - Long entries scroll with the mouse wheel once the pointer is in the tooltip.
+//! - Locked tooltips stay open when another tooltip opens, up to a configurable number of pinned tooltips.
+//! - Locked tooltips can be dragged by their title, with events to save where they were dropped.
+//! - Escape closes the top most tooltip and pressing outside closes unlocked tooltips opened by pressing, both configurable.
+//! - Keyboard and gamepad navigation using input focus, for when there is no pointer.
+//! - Opening and closing phases with configurable durations, so tooltips can be animated in and out.
+//! - Open, close and lock tooltips from code with `TooltipCommands`, for tutorials and scripted events.
 //! - Highlight other Entites using a linked text, highlight designs are up to you.
 //!
 //! ## Usage
@@ -26,7 +38,9 @@
 //! ### (Optional) Configure tooltips
 //! ```rust
 //!     commands.insert_resource(TooltipConfiguration {
-//!         activation_method: ActivationMethod::MiddleMouse,
+//!         activation_method: ActivationMethod::Press {
+//!             button: PointerButton::Middle,
+//!         },
 //!         ..Default::default()
 //!     });
 //! ```
@@ -124,78 +138,125 @@
 //! }
 //! ```
 
+mod aim;
+pub mod commands;
 pub mod events;
+pub mod focus;
 pub mod highlight;
+pub mod history;
 pub mod layout;
+pub mod lifecycle;
+pub mod placement;
 pub mod query;
 pub mod term;
 
 use std::time::Duration;
 
 use bevy_app::{Plugin, PreStartup, Update};
+use bevy_camera::{Camera, visibility::Visibility};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     children,
     component::Component,
     entity::Entity,
     event::{EntityEvent, Event},
+    hierarchy::{ChildOf, Children},
     lifecycle::HookContext,
+    message::MessageReader,
     observer::{Observer, On},
-    query::{AnyOf, Has, Or, QueryData, With},
+    query::{AnyOf, Has, Or, QueryData, With, Without},
     resource::Resource,
     schedule::{IntoScheduleConfigs, common_conditions::resource_changed},
-    system::{Commands, Query, Res},
+    system::{Commands, Query, Res, SystemParam},
     world::World,
 };
 
+use bevy_input::{ButtonInput, keyboard::KeyCode, mouse::MouseScrollUnit};
+use bevy_input_focus::InputFocus;
 use bevy_log::error;
 use bevy_math::{Rect, Vec2};
 use bevy_picking::{
     Pickable,
-    events::{Move, Out, Pointer, Press},
-    pointer::PointerButton,
+    events::{Move, Out, Pointer, Press, Scroll},
+    pointer::{Location, PointerButton, PointerId, PointerLocation},
 };
 use bevy_platform::collections::HashMap;
-use bevy_text::TextSpan;
+use bevy_text::{LineHeight, TextFont, TextSpan};
 use bevy_time::{Time, Timer, TimerMode};
 use bevy_ui::{
-    Display, GlobalZIndex, GridAutoFlow, Node, PositionType, RelativeCursorPosition, UiRect, Val,
-    widget::Text,
+    ComputedNode, Display, GlobalZIndex, GridAutoFlow, GridTrack, Node, Overflow, PositionType,
+    RelativeCursorPosition, ScrollPosition, UiRect, UiScale, UiTargetCamera, Val, widget::Text,
 };
-use bevy_window::Window;
 use tiny_bail::prelude::*;
 
 /// An easy way to import commonly used types.
 pub mod prelude {
     pub use super::{
-        ActivationMethod, NestedTooltipPlugin, Tooltip, TooltipConfiguration, TooltipMap,
-        TooltipSpawned, TooltipsContent, TooltipsData,
-        events::{TooltipHighlighting, TooltipLocked},
+        ActivationMethod, DepthLimit, NestedTiming, NestedTooltipPlugin, NestingMode, PressOutside,
+        Tooltip, TooltipConfiguration, TooltipDebounce, TooltipDepth, TooltipMap, TooltipSpawned,
+        TooltipsContent, TooltipsData,
+        commands::{TooltipCommands, TooltipOpenAt},
+        events::{
+            TooltipDragged, TooltipDropped, TooltipHighlighting, TooltipLocked, TooltipMoved,
+        },
+        focus::{FocusNavigation, TooltipFocusOpened},
         highlight::{TooltipHighlight, TooltipHighlightLink},
+        history::TooltipHistory,
         layout::{TooltipStringText, TooltipTextNode, TooltipTitleNode, TooltipTitleText},
+        lifecycle::{TooltipClosing, TooltipOpen, TooltipOpening},
+        placement::{TooltipCaret, TooltipPlacement, TooltipSide},
         query::{TooltipEntities, TooltipEntitiesParam},
         term::{TooltipTermLink, TooltipTermLinkRecursive},
     };
 }
 use prelude::*;
 
-use crate::{highlight::HighlightPlugin, term::hover_time_spawn};
+use crate::{
+    aim::{AimPlugin, TooltipAiming},
+    focus::{FocusPlugin, TooltipRefocus},
+    highlight::HighlightPlugin,
+    layout::TooltipTextScroll,
+    lifecycle::{CloseTooltip, LifecyclePlugin},
+    placement::{
+        LinkRectParam, PlacementPlugin, TOOLTIP_OFFSET, TooltipCursorPosition, TooltipPlacing,
+    },
+    term::{
+        HoverWarmUp, hover_intent_move, hover_time_spawn, long_press_cancel, long_press_spawn,
+        track_warm_up,
+    },
+};
 
 /// This plugin adds systems and resources that makes the logic work.
 pub struct NestedTooltipPlugin;
 
 impl Plugin for NestedTooltipPlugin {
     fn build(&self, app: &mut bevy_app::App) {
-        app.add_plugins(HighlightPlugin)
-            .init_resource::<TooltipConfiguration>()
-            .init_resource::<TooltipReference>()
-            .add_systems(PreStartup, setup_component_hooks)
-            .add_systems(Update, tick_timers)
-            .add_systems(
-                Update,
-                update_settings.run_if(resource_changed::<TooltipConfiguration>),
-            )
-            .add_observer(spawn_time_done);
+        app.add_plugins((
+            HighlightPlugin,
+            PlacementPlugin,
+            FocusPlugin,
+            LifecyclePlugin,
+            AimPlugin,
+        ))
+        .init_resource::<TooltipConfiguration>()
+        .init_resource::<TooltipReference>()
+        .init_resource::<HoverWarmUp>()
+        .add_systems(PreStartup, setup_component_hooks)
+        .add_systems(
+            Update,
+            (
+                tick_timers,
+                modifier_release,
+                press_outside_close,
+                close_key_close,
+                track_warm_up,
+            ),
+        )
+        .add_systems(
+            Update,
+            update_settings.run_if(resource_changed::<TooltipConfiguration>),
+        )
+        .add_observer(spawn_time_done);
     }
 }
 
@@ -205,20 +266,109 @@ pub struct TooltipConfiguration {
     /// See the [`ActivationMethod`] variants.
     pub activation_method: ActivationMethod,
 
+    /// See the [`TooltipPlacement`] variants.
+    pub placement: TooltipPlacement,
+
+    /// How tooltips spawned from inside another tooltip are placed.
+    /// By default they cascade beside their parent so they do not cover it.
+    pub nested_placement: TooltipPlacement,
+
+    /// Whether links inside tooltips open nested tooltips or replace the content of their tooltip.
+    pub nesting: NestingMode,
+
+    /// Pressing this button on a [`Tooltip`] adds or removes [`TooltipLocked`].
+    pub lock_button: PointerButton,
+
+    /// Pressing this key closes the top most [`Tooltip`], even if it is locked.
+    pub close_key: Option<KeyCode>,
+
+    /// What a press outside of every [`Tooltip`] closes.
+    pub press_outside: PressOutside,
+
+    /// Open tooltips when their link gains input focus and navigate between links with directional
+    /// input, for keyboards and gamepads. See [`FocusNavigation`].
+    pub focus_navigation: Option<FocusNavigation>,
+
+    /// Spawn a [`TooltipCaret`] in each tooltip that points to what spawned it.
+    pub caret: bool,
+
     /// Maximum amount of time the `ToolTip` will remain around without user interaction.
     pub interaction_wait_for_time: Duration,
 
+    /// After a tooltip has been open, hovering another link within this time opens its tooltip
+    /// without waiting, like tooltips of operating systems. Only applies to links outside tooltips.
+    pub hover_skip_delay: Option<Duration>,
+
+    /// Link timers only progress while the pointer moves over the link slower than this speed,
+    /// in logical pixels per second. This stops tooltips opening as the pointer sweeps past links.
+    pub hover_intent_speed: Option<f32>,
+
+    /// While the pointer moves from a link towards its tooltip, within the triangle between the link
+    /// and the near edge of the tooltip, links the pointer crosses do not open tooltips and the
+    /// tooltip does not time out. This ends once the pointer rests for `aim_timeout`.
+    pub safe_triangle: bool,
+
+    /// How far into a tooltip the pointer has to go before leaving closes it.
+    pub debounce: TooltipDebounce,
+
+    /// How long the pointer can rest inside the `safe_triangle` before it stops aiming.
+    pub aim_timeout: Duration,
+
+    /// Hover time of links inside tooltips, based on the hover time of the [`ActivationMethod`].
+    pub nested_hover_time: NestedTiming,
+
+    /// Time nested tooltips remain without user interaction,
+    /// based on `interaction_wait_for_time`.
+    pub nested_interaction_wait_for_time: NestedTiming,
+
+    /// How long a [`Tooltip`] stays [`TooltipOpening`] before it is [`TooltipOpen`].
+    pub opening_time: Duration,
+
+    /// How long a [`Tooltip`] stays [`TooltipClosing`] before it is despawned,
+    /// zero despawns it straight away.
+    pub closing_time: Duration,
+
     /// The starting z_index this will be incremented for each recursive tooltip
     /// increase this if tooltips are not on top and you want to fix that.
     pub starting_z_index: i32,
+
+    /// Deepest a [`Tooltip`] can be nested, top level tooltips are depth 0.
+    pub max_depth: Option<usize>,
+
+    /// What happens when a link would open a tooltip deeper than `max_depth`.
+    pub depth_limit: DepthLimit,
+
+    /// How many locked top level tooltips, with the tooltips nested under them, stay open when
+    /// another top level tooltip opens. Past this the oldest are closed, 0 closes all of them.
+    pub max_pinned: usize,
 }
 
 impl Default for TooltipConfiguration {
     fn default() -> Self {
         Self {
             activation_method: Default::default(),
+            placement: Default::default(),
+            nested_placement: TooltipPlacement::Cascade,
+            nesting: NestingMode::Nested,
+            lock_button: PointerButton::Middle,
+            close_key: Some(KeyCode::Escape),
+            press_outside: PressOutside::PressActivated,
+            focus_navigation: None,
+            caret: false,
             interaction_wait_for_time: Duration::from_secs_f64(0.8),
+            hover_skip_delay: None,
+            hover_intent_speed: None,
+            safe_triangle: false,
+            debounce: TooltipDebounce::default(),
+            aim_timeout: Duration::from_millis(300),
+            nested_hover_time: NestedTiming::Same,
+            nested_interaction_wait_for_time: NestedTiming::Same,
+            opening_time: Duration::ZERO,
+            closing_time: Duration::ZERO,
             starting_z_index: 3,
+            max_depth: None,
+            depth_limit: DepthLimit::Ignore,
+            max_pinned: 3,
         }
     }
 }
@@ -227,8 +377,22 @@ impl Default for TooltipConfiguration {
 /// Hovering can be further customised.
 #[derive(Debug, Clone)]
 pub enum ActivationMethod {
+    /// A pointer button such as [`PointerButton::Middle`] is pressed.
+    Press { button: PointerButton },
     /// Middle mouse button is pressed.
+    #[deprecated(note = "use `ActivationMethod::Press { button: PointerButton::Middle }`")]
     MiddleMouse,
+    /// Mouse is over the `Tooltip` for a duration while any of `keys` are held,
+    /// a `time` of zero opens the tooltip as soon as the key is held.
+    /// If `close_on_release` is set unlocked tooltips are closed when the keys are released.
+    Modifier {
+        keys: Vec<KeyCode>,
+        time: Duration,
+        close_on_release: bool,
+    },
+    /// The link is pressed and held for a duration, for touch screens.
+    /// Tooltips stay open until a press lands outside of every tooltip, see [`PressOutside`].
+    LongPress { time: Duration },
     /// Mouse is over the `Tooltip` for a duration.
     Hover { time: Duration },
 }
@@ -241,6 +405,119 @@ impl Default for ActivationMethod {
     }
 }
 
+impl ActivationMethod {
+    /// The pointer button that opens tooltips, none if they are not opened by pressing.
+    #[allow(deprecated)]
+    fn press_button(&self) -> Option<PointerButton> {
+        match self {
+            ActivationMethod::Press { button } => Some(*button),
+            ActivationMethod::MiddleMouse => Some(PointerButton::Middle),
+            _ => None,
+        }
+    }
+}
+
+/// What happens when a link inside a [`Tooltip`] is activated.
+#[derive(Debug, Clone, Default)]
+pub enum NestingMode {
+    /// A new tooltip is opened nested under the tooltip holding the link.
+    #[default]
+    Nested,
+    /// The content of the tooltip holding the link is replaced, like following a link on a wiki.
+    /// Visited terms are kept in its [`TooltipHistory`] for going back and forward.
+    InPlace,
+}
+
+/// What happens when a link would open a [`Tooltip`] deeper than
+/// [`TooltipConfiguration::max_depth`].
+#[derive(Debug, Clone, Default)]
+pub enum DepthLimit {
+    /// The link does nothing.
+    #[default]
+    Ignore,
+    /// The deepest tooltip, the one holding the link, is closed and replaced by the new tooltip.
+    /// The new tooltip is opened from the link the closed tooltip was opened from.
+    ReplaceDeepest,
+    /// The oldest tooltip in the chain is closed to make room for the new tooltip.
+    CloseOldest,
+}
+
+/// What a press outside of every [`Tooltip`] closes.
+#[derive(Debug, Clone, Default)]
+pub enum PressOutside {
+    /// Presses outside do not close tooltips.
+    Ignore,
+    /// Closes the tooltips that are not locked when tooltips are opened by pressing,
+    /// with [`ActivationMethod::LongPress`] or [`ActivationMethod::Press`], otherwise
+    /// presses outside do not close tooltips.
+    #[default]
+    PressActivated,
+    /// Closes the tooltips that are not locked.
+    CloseUnlocked,
+    /// Closes every tooltip, even locked ones.
+    CloseAll,
+}
+
+/// How far into a [`Tooltip`] the pointer has to go before leaving it closes the tooltip,
+/// this stops tooltips closing when the pointer only clips their edge.
+#[derive(Debug, Clone)]
+pub enum TooltipDebounce {
+    /// The pointer has to be inside this fraction of the tooltips size from its centre,
+    /// 0.5 is the edge.
+    Fraction(f32),
+    /// The pointer has to be at least this many logical pixels inside the edge of the tooltip.
+    Inset(f32),
+    /// Entering the tooltip is enough.
+    Immediate,
+}
+
+impl Default for TooltipDebounce {
+    fn default() -> Self {
+        Self::Fraction(0.48)
+    }
+}
+
+/// How a time changes for tooltips nested inside other tooltips, by how deeply they are nested.
+/// Depth 0 is a top level tooltip, 1 is a tooltip nested in it and so on.
+#[derive(Debug, Clone)]
+pub enum NestedTiming {
+    /// The same time as top level tooltips.
+    Same,
+    /// A time for each depth starting at depth 1, the last time is used for any deeper tooltips.
+    PerDepth(Vec<Duration>),
+    /// The time is multiplied by `factor` for each depth but never less than `min`.
+    /// A negative or NaN `factor` gives `min`, times too long for a [`Duration`] saturate.
+    Scaled { factor: f32, min: Duration },
+}
+
+impl NestedTiming {
+    /// The time for a tooltip at `depth` given the `top_level` time.
+    pub fn time(&self, top_level: Duration, depth: usize) -> Duration {
+        if depth == 0 {
+            return top_level;
+        }
+        match self {
+            NestedTiming::Same => top_level,
+            NestedTiming::PerDepth(times) => times
+                .get(depth - 1)
+                .or(times.last())
+                .copied()
+                .unwrap_or(top_level),
+            NestedTiming::Scaled { factor, min } => {
+                let depth = i32::try_from(depth).unwrap_or(i32::MAX);
+                let scaled = top_level.as_secs_f32() * factor.powi(depth);
+                Duration::try_from_secs_f32(scaled)
+                    .unwrap_or(if scaled > 0. {
+                        Duration::MAX
+                    } else {
+                        Duration::ZERO
+                    })
+                    .max(*min)
+            }
+        }
+    }
+}
+
 /// Default node for the [`Tooltip`] node use this to layout your tooltips without
 /// accidentally moving it's position.
 /// This resource is initialised on adding plugin.
@@ -263,6 +540,8 @@ impl Default for TooltipReference {
                 position_type: PositionType::Absolute,
                 display: Display::Grid,
                 grid_auto_flow: GridAutoFlow::Row,
+                // The text row shrinks to fit so it can scroll
+                grid_template_rows: vec![GridTrack::auto(), GridTrack::flex(1.)],
                 max_width: Val::Vw(35.),
                 min_height: Val::Vh(5.),
                 max_height: Val::Vh(20.),
@@ -279,13 +558,35 @@ impl Default for TooltipReference {
 #[derive(Debug, Component)]
 #[require(RelativeCursorPosition)]
 pub struct Tooltip {
-    entity: Entity,
+    link: Option<Entity>,
+    /// When the tooltip was opened, the oldest pinned tooltips are closed first.
+    opened: Duration,
 }
 
 impl Tooltip {
     /// The entity that spawned this tooltip
+    /// Tooltips opened at a position with [`TooltipCommands`] have no link and
+    /// return [`Entity::PLACEHOLDER`].
+    #[deprecated(note = "use `Tooltip::link`, tooltips opened at a position have no link")]
     pub fn entity(&self) -> Entity {
-        self.entity
+        self.link.unwrap_or(Entity::PLACEHOLDER)
+    }
+
+    /// The entity that spawned this tooltip, none for tooltips opened at a position with
+    /// [`TooltipCommands`].
+    pub fn link(&self) -> Option<Entity> {
+        self.link
+    }
+}
+
+/// How deeply a [`Tooltip`] is nested, top level tooltips are depth 0.
+#[derive(Debug, Component)]
+pub struct TooltipDepth(pub(crate) usize);
+
+impl TooltipDepth {
+    /// The depth of the tooltip.
+    pub fn depth(&self) -> usize {
+        self.0
     }
 }
 
@@ -295,6 +596,7 @@ impl Tooltip {
 struct ToolTipDebounced;
 
 /// This is sent when a [`Tooltip`] is spawned.
+/// With [`NestingMode::InPlace`] it is sent again when the content of the tooltip is replaced.
 #[derive(Debug, EntityEvent)]
 pub struct TooltipSpawned {
     pub entity: Entity,
@@ -322,12 +624,20 @@ pub struct TooltipsNestedOf(Entity);
 #[derive(Debug, Component)]
 pub struct TooltipLinkTimer {
     timer: Timer,
+    /// The pointer hovering the link, the tooltip spawns where this pointer is.
+    pointer_id: PointerId,
+    /// The camera the link was picked with, the tooltip will be rendered by this camera.
+    camera: Entity,
+    /// How far the pointer has moved over the link since the timer was last ticked.
+    moved: f32,
 }
 
 /// Sent when link has been hovered long enough to spawn [`ToolTip`].
 #[derive(Event)]
 struct TooltipLinkTimeElapsed {
     term_entity: Entity,
+    pointer_id: PointerId,
+    camera: Entity,
 }
 
 /// The data of your tooltips.
@@ -371,17 +681,22 @@ pub enum TooltipsContent {
     Highlight(String),
 }
 
-/// Marker for Observers related to middle mouse triggering of tooltips
+/// Marker for Observers related to button press triggering of tooltips
 #[derive(Component)]
-struct NestedTooltipsMiddleMouseObserver;
+struct NestedTooltipsPressObserver;
 
 /// Marker for Observers related to hover triggering of tooltips
 #[derive(Component)]
 struct NestedTooltipsHoverObserver;
 
+/// Marker for Observers related to long press triggering of tooltips
+#[derive(Component)]
+struct NestedTooltipsLongPressObserver;
+
 /// Setup hooks so that interactions will work
 /// This is based on resource setting
 /// If setting is changed then an update system will set the correct observers
+#[allow(deprecated)]
 fn setup_component_hooks(world: &mut World) {
     world
         .register_component_hooks::<TooltipTermLink>()
@@ -389,16 +704,18 @@ fn setup_component_hooks(world: &mut World) {
             let config = rq!(world.get_resource::<TooltipConfiguration>());
 
             match config.activation_method {
-                ActivationMethod::MiddleMouse => {
-                    let middle_observe = Observer::new(middle_mouse_spawn).with_entity(entity);
+                ActivationMethod::Press { .. } | ActivationMethod::MiddleMouse => {
+                    let press_observe = Observer::new(press_spawn).with_entity(entity);
                     world
                         .commands()
-                        .spawn((middle_observe, NestedTooltipsMiddleMouseObserver));
+                        .spawn((press_observe, NestedTooltipsPressObserver));
                 }
-                ActivationMethod::Hover { .. } => {
+                ActivationMethod::Hover { .. } | ActivationMethod::Modifier { .. } => {
                     let hover_spawn_observer = Observer::new(hover_time_spawn).with_entity(entity);
                     let hover_cancel_observer =
                         Observer::new(hover_cancel_spawn).with_entity(entity);
+                    let hover_intent_observer =
+                        Observer::new(hover_intent_move).with_entity(entity);
 
                     world
                         .commands()
@@ -406,6 +723,27 @@ fn setup_component_hooks(world: &mut World) {
                     world
                         .commands()
                         .spawn((hover_cancel_observer, NestedTooltipsHoverObserver));
+                    world
+                        .commands()
+                        .spawn((hover_intent_observer, NestedTooltipsHoverObserver));
+                }
+                ActivationMethod::LongPress { .. } => {
+                    let long_press_spawn_observer =
+                        Observer::new(long_press_spawn).with_entity(entity);
+                    let long_press_cancel_observer =
+                        Observer::new(long_press_cancel).with_entity(entity);
+                    let hover_cancel_observer =
+                        Observer::new(hover_cancel_spawn).with_entity(entity);
+
+                    world
+                        .commands()
+                        .spawn((long_press_spawn_observer, NestedTooltipsLongPressObserver));
+                    world
+                        .commands()
+                        .spawn((long_press_cancel_observer, NestedTooltipsLongPressObserver));
+                    world
+                        .commands()
+                        .spawn((hover_cancel_observer, NestedTooltipsLongPressObserver));
                 }
             }
         });
@@ -416,16 +754,18 @@ fn setup_component_hooks(world: &mut World) {
             let config = rq!(world.get_resource::<TooltipConfiguration>());
 
             match config.activation_method {
-                ActivationMethod::MiddleMouse => {
-                    let middle_observe = Observer::new(middle_mouse_spawn).with_entity(entity);
+                ActivationMethod::Press { .. } | ActivationMethod::MiddleMouse => {
+                    let press_observe = Observer::new(press_spawn).with_entity(entity);
                     world
                         .commands()
-                        .spawn((middle_observe, NestedTooltipsMiddleMouseObserver));
+                        .spawn((press_observe, NestedTooltipsPressObserver));
                 }
-                ActivationMethod::Hover { .. } => {
+                ActivationMethod::Hover { .. } | ActivationMethod::Modifier { .. } => {
                     let hover_spawn_observer = Observer::new(hover_time_spawn).with_entity(entity);
                     let hover_cancel_observer =
                         Observer::new(hover_cancel_spawn).with_entity(entity);
+                    let hover_intent_observer =
+                        Observer::new(hover_intent_move).with_entity(entity);
 
                     world
                         .commands()
@@ -433,6 +773,27 @@ fn setup_component_hooks(world: &mut World) {
                     world
                         .commands()
                         .spawn((hover_cancel_observer, NestedTooltipsHoverObserver));
+                    world
+                        .commands()
+                        .spawn((hover_intent_observer, NestedTooltipsHoverObserver));
+                }
+                ActivationMethod::LongPress { .. } => {
+                    let long_press_spawn_observer =
+                        Observer::new(long_press_spawn).with_entity(entity);
+                    let long_press_cancel_observer =
+                        Observer::new(long_press_cancel).with_entity(entity);
+                    let hover_cancel_observer =
+                        Observer::new(hover_cancel_spawn).with_entity(entity);
+
+                    world
+                        .commands()
+                        .spawn((long_press_spawn_observer, NestedTooltipsLongPressObserver));
+                    world
+                        .commands()
+                        .spawn((long_press_cancel_observer, NestedTooltipsLongPressObserver));
+                    world
+                        .commands()
+                        .spawn((hover_cancel_observer, NestedTooltipsLongPressObserver));
                 }
             }
         });
@@ -444,37 +805,67 @@ fn setup_component_hooks(world: &mut World) {
                 .entity(entity)
                 .observe(toggle_lock)
                 .observe(hover_debounce)
-                .observe(hover_despawn);
+                .observe(hover_despawn)
+                .observe(scroll_tooltip);
         },
     );
 }
 
 /// Updates the observers to match user settings
 /// this will despawn unused observers
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, deprecated)]
 fn update_settings(
     config: Res<TooltipConfiguration>,
     term_links: Query<Entity, Or<(With<TooltipTermLink>, With<TooltipTermLinkRecursive>)>>,
+    observers_query: Query<
+        Entity,
+        Or<(
+            With<NestedTooltipsPressObserver>,
+            With<NestedTooltipsHoverObserver>,
+            With<NestedTooltipsLongPressObserver>,
+        )>,
+    >,
     mut commands: Commands,
 ) {
+    // Otherwise every change to the configuration adds another observer to each link
+    for observer in observers_query {
+        c!(commands.get_entity(observer)).despawn();
+    }
     match config.activation_method {
-        ActivationMethod::MiddleMouse => {
-            let mut middle_observe = Observer::new(middle_mouse_spawn);
+        ActivationMethod::Press { .. } | ActivationMethod::MiddleMouse => {
+            let mut press_observe = Observer::new(press_spawn);
             for entity in term_links {
-                middle_observe.watch_entity(entity);
+                press_observe.watch_entity(entity);
             }
-            commands.spawn((middle_observe, NestedTooltipsMiddleMouseObserver));
+            commands.spawn((press_observe, NestedTooltipsPressObserver));
         }
-        ActivationMethod::Hover { .. } => {
+        ActivationMethod::Hover { .. } | ActivationMethod::Modifier { .. } => {
             let mut hover_spawn_observer = Observer::new(hover_time_spawn);
             let mut hover_cancel_observer = Observer::new(hover_cancel_spawn);
+            let mut hover_intent_observer = Observer::new(hover_intent_move);
 
             for entity in term_links {
                 hover_spawn_observer.watch_entity(entity);
                 hover_cancel_observer.watch_entity(entity);
+                hover_intent_observer.watch_entity(entity);
             }
             commands.spawn((hover_spawn_observer, NestedTooltipsHoverObserver));
             commands.spawn((hover_cancel_observer, NestedTooltipsHoverObserver));
+            commands.spawn((hover_intent_observer, NestedTooltipsHoverObserver));
+        }
+        ActivationMethod::LongPress { .. } => {
+            let mut long_press_spawn_observer = Observer::new(long_press_spawn);
+            let mut long_press_cancel_observer = Observer::new(long_press_cancel);
+            let mut hover_cancel_observer = Observer::new(hover_cancel_spawn);
+
+            for entity in term_links {
+                long_press_spawn_observer.watch_entity(entity);
+                long_press_cancel_observer.watch_entity(entity);
+                hover_cancel_observer.watch_entity(entity);
+            }
+            commands.spawn((long_press_spawn_observer, NestedTooltipsLongPressObserver));
+            commands.spawn((long_press_cancel_observer, NestedTooltipsLongPressObserver));
+            commands.spawn((hover_cancel_observer, NestedTooltipsLongPressObserver));
         }
     }
 }
@@ -505,54 +896,165 @@ struct HoverWaitQuery {
     entity: Entity,
     tooltip: &'static Tooltip,
     wait_for: &'static mut TooltipWaitForHover,
+    aiming: Has<TooltipAiming>,
 }
 
 /// Tick timers and if they finish spawn/despawn the releveant tooltip.
+/// Link timers only tick while the [`ActivationMethod::Modifier`] keys are held
+/// and the pointer is slower than the hover intent speed.
+/// While a pointer is aiming for a tooltip the timers of links it is over do not tick
+/// and the tooltip does not time out.
 fn tick_timers(
     mut links_query: Query<SpawnLinksQuery>,
     mut wait_for_query: Query<HoverWaitQuery>,
+    aiming_query: Query<&TooltipAiming>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    tooltip_configuration: Res<TooltipConfiguration>,
     time_res: Res<Time>,
     mut commands: Commands,
 ) {
+    let modifier_held = match &tooltip_configuration.activation_method {
+        ActivationMethod::Modifier { keys, .. } => {
+            keyboard.is_some_and(|keyboard| keyboard.any_pressed(keys.iter().copied()))
+        }
+        _ => true,
+    };
     for mut links_item in &mut links_query {
+        let moved = std::mem::take(&mut links_item.spawn_timer.moved);
+        let too_fast = time_res.delta_secs() > 0.0
+            && tooltip_configuration
+                .hover_intent_speed
+                .is_some_and(|speed| moved / time_res.delta_secs() > speed);
+        let aiming = aiming_query
+            .iter()
+            .any(|aiming| aiming.pointer_id == links_item.spawn_timer.pointer_id);
+        if !modifier_held || too_fast || aiming {
+            links_item.spawn_timer.timer.reset();
+            continue;
+        }
         links_item.spawn_timer.timer.tick(time_res.delta());
         if links_item.spawn_timer.timer.is_finished() {
             commands.trigger(TooltipLinkTimeElapsed {
                 term_entity: links_item.entity,
+                pointer_id: links_item.spawn_timer.pointer_id,
+                camera: links_item.spawn_timer.camera,
             });
             c!(commands.get_entity(links_item.entity)).remove::<TooltipLinkTimer>();
         }
     }
     for mut wait_for_item in &mut wait_for_query {
+        if wait_for_item.aiming {
+            continue;
+        }
         wait_for_item.wait_for.timer.tick(time_res.delta());
         if wait_for_item.wait_for.timer.is_finished() {
-            c!(commands.get_entity(wait_for_item.entity)).try_despawn();
+            commands.trigger(CloseTooltip {
+                entity: wait_for_item.entity,
+            });
         }
     }
 }
 
+/// When the [`ActivationMethod::Modifier`] keys are released close unlocked tooltips
+/// if configured to.
+fn modifier_release(
+    tooltip_query: Query<Entity, (With<Tooltip>, Without<TooltipLocked>)>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut commands: Commands,
+) {
+    let ActivationMethod::Modifier {
+        keys,
+        close_on_release: true,
+        ..
+    } = &tooltip_configuration.activation_method
+    else {
+        return;
+    };
+    let keyboard = rq!(keyboard);
+    if !keyboard.any_just_released(keys.iter().copied())
+        || keyboard.any_pressed(keys.iter().copied())
+    {
+        return;
+    }
+    for entity in tooltip_query {
+        commands.trigger(CloseTooltip { entity });
+    }
+}
+
 /// Triggered when timer is done, fetch additional data to spawn [`ToolTip`].
-#[allow(clippy::too_many_arguments)]
+/// Long pressed tooltips do not time out as touch pointers do not hover.
 fn spawn_time_done(
     term: On<TooltipLinkTimeElapsed>,
-    links_query: Query<AnyOf<(&TooltipTermLink, &TooltipTermLinkRecursive)>>,
-    existing_tooltips_query: Query<(Entity, &Tooltip)>,
-    window_query: Query<&Window>,
-    tooltips_map: Res<TooltipMap>,
-    tooltip_reference: Res<TooltipReference>,
+    pointer_query: Query<(&PointerId, &PointerLocation)>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut spawner: TooltipSpawner,
+) {
+    let location = r!(pointer_location(term.pointer_id, pointer_query));
+    let tooltip = rq!(spawner.spawn_at_location(term.term_entity, &location, term.camera));
+    if let ActivationMethod::LongPress { .. } = tooltip_configuration.activation_method {
+        r!(spawner.commands().get_entity(tooltip)).remove::<TooltipWaitForHover>();
+    }
+}
+
+/// A press outside of every [`Tooltip`] closes them as configured by [`PressOutside`].
+/// Presses on links, or on the children of links such as text spans, are ignored so they can open
+/// their tooltip.
+#[allow(clippy::type_complexity)]
+fn press_outside_close(
+    mut press_reader: MessageReader<Pointer<Press>>,
+    tooltip_query: Query<(Entity, Has<TooltipLocked>), With<Tooltip>>,
+    links_query: Query<(), Or<(With<TooltipTermLink>, With<TooltipTermLinkRecursive>)>>,
+    ancestor_query: Query<&ChildOf>,
     tooltip_configuration: Res<TooltipConfiguration>,
     mut commands: Commands,
 ) {
-    spawn_tooltip(
-        term.term_entity,
-        links_query,
-        existing_tooltips_query,
-        window_query,
-        tooltips_map,
-        tooltip_reference,
-        tooltip_configuration,
-        &mut commands,
-    );
+    let pressed_outside = press_reader.read().any(|press| {
+        let on_link = std::iter::once(press.entity)
+            .chain(ancestor_query.iter_ancestors(press.entity))
+            .any(|entity| links_query.contains(entity));
+        !on_link && !tooltip_query.contains(ancestor_query.root_ancestor(press.entity))
+    });
+    if !pressed_outside {
+        return;
+    }
+    let press_activated = matches!(
+        tooltip_configuration.activation_method,
+        ActivationMethod::LongPress { .. }
+    ) || tooltip_configuration
+        .activation_method
+        .press_button()
+        .is_some();
+    for (entity, locked) in tooltip_query {
+        let close = match tooltip_configuration.press_outside {
+            PressOutside::Ignore => false,
+            PressOutside::PressActivated => press_activated && !locked,
+            PressOutside::CloseUnlocked => !locked,
+            PressOutside::CloseAll => true,
+        };
+        if close {
+            commands.trigger(CloseTooltip { entity });
+        }
+    }
+}
+
+/// Tooltips that can still be closed.
+type OpenTooltipFilter = (With<Tooltip>, Without<TooltipClosing>);
+
+/// When the close key is pressed close the top most [`Tooltip`], even if it is locked.
+fn close_key_close(
+    tooltip_query: Query<(Entity, &GlobalZIndex), OpenTooltipFilter>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut commands: Commands,
+) {
+    let close_key = rq!(tooltip_configuration.close_key);
+    let keyboard = rq!(keyboard);
+    if !keyboard.just_pressed(close_key) {
+        return;
+    }
+    let (entity, _) = rq!(tooltip_query.iter().max_by_key(|(_, z_index)| z_index.0));
+    commands.trigger(CloseTooltip { entity });
 }
 
 #[derive(QueryData)]
@@ -560,30 +1062,37 @@ struct TooltipDebounceQuery {
     tooltip: &'static Tooltip,
     debounced: Has<ToolTipDebounced>,
     cursor: &'static RelativeCursorPosition,
+    computed: &'static ComputedNode,
 }
 
 /// This is to debounce the cursor when it lands on the
 /// tooltip, without this it is too easy to accidentally
 /// close the tooltip.
+/// How far in the pointer needs to be is set with [`TooltipDebounce`].
 fn hover_debounce(
     hover: On<Pointer<Move>>,
     tooltip_query: Query<TooltipDebounceQuery>,
+    tooltip_configuration: Res<TooltipConfiguration>,
     mut commands: Commands,
 ) {
-    // Number should not be greater then 0.5
-    // the low the number the more in the tooltip, the pointer needs to be
-    const DEBOUNCE_DIST: f32 = 0.48;
     let tooltip_item = r!(tooltip_query.get(hover.entity));
     if tooltip_item.debounced {
         return;
     }
     let normalised = rq!(tooltip_item.cursor.normalized);
-    let bounds = Rect {
-        min: Vec2::new(-DEBOUNCE_DIST, -DEBOUNCE_DIST),
-        max: Vec2::new(DEBOUNCE_DIST, DEBOUNCE_DIST),
+    let inside = match tooltip_configuration.debounce {
+        TooltipDebounce::Fraction(fraction) => {
+            Rect::from_center_half_size(Vec2::ZERO, Vec2::splat(fraction)).contains(normalised)
+        }
+        TooltipDebounce::Inset(inset) => {
+            let size = tooltip_item.computed.size() * tooltip_item.computed.inverse_scale_factor();
+            let half_size = (size / 2.0 - inset).max(Vec2::ZERO);
+            Rect::from_center_half_size(Vec2::ZERO, half_size).contains(normalised * size)
+        }
+        TooltipDebounce::Immediate => true,
     };
 
-    if bounds.contains(normalised) {
+    if inside {
         r!(commands.get_entity(hover.entity))
             .insert(ToolTipDebounced)
             .remove::<TooltipWaitForHover>();
@@ -594,199 +1103,594 @@ fn hover_debounce(
 struct TooltipQuery {
     tooltip: &'static Tooltip,
     relative_cursor: &'static RelativeCursorPosition,
-    has_nested: Has<TooltipsNested>,
+    nested: Option<&'static TooltipsNested>,
     locked: Has<TooltipLocked>,
     debounced: Has<ToolTipDebounced>,
 }
 
+#[derive(QueryData)]
+struct ScrollTooltipQuery {
+    children: &'static Children,
+    debounced: Has<ToolTipDebounced>,
+    locked: Has<TooltipLocked>,
+}
+
+/// When the mouse wheel is used over a debounced or locked [`ToolTip`]
+/// scroll its [`TooltipTextNode`] if the text does not fit, a line of its text at a time.
+fn scroll_tooltip(
+    mut scroll: On<Pointer<Scroll>>,
+    tooltip_query: Query<ScrollTooltipQuery, With<Tooltip>>,
+    mut scroll_query: Query<
+        (&mut ScrollPosition, &ComputedNode, &Children),
+        With<TooltipTextScroll>,
+    >,
+    font_query: Query<(&TextFont, &LineHeight), With<TooltipTextNode>>,
+) {
+    let tooltip_item = r!(tooltip_query.get(scroll.entity));
+    if !tooltip_item.debounced && !tooltip_item.locked {
+        return;
+    }
+    let mut scroll_nodes = scroll_query.iter_many_mut(tooltip_item.children);
+    let (mut scroll_position, computed, scroll_children) = rq!(scroll_nodes.fetch_next());
+    let mut delta_y = -scroll.y;
+    if scroll.unit == MouseScrollUnit::Line {
+        let (font, line_height) = rq!(font_query.iter_many(scroll_children).next());
+        delta_y *= match *line_height {
+            LineHeight::Px(px) => px,
+            LineHeight::RelativeToFont(scale) => scale * font.font_size,
+        };
+    }
+    let max_offset = ((computed.content_size() - computed.size())
+        * computed.inverse_scale_factor())
+    .y
+    .max(0.);
+    if max_offset == 0. {
+        return;
+    }
+    scroll_position.y = (scroll_position.y + delta_y).clamp(0., max_offset);
+    scroll.propagate(false);
+}
+
 /// When user mouses out of [`ToolTip`] despawn it unless it has a nested tooltip.
+/// Nested tooltips that are closing do not keep it open.
 fn hover_despawn(
     hover: On<Pointer<Out>>,
     tooltip_query: Query<TooltipQuery>,
+    closing_query: Query<(), With<TooltipClosing>>,
     mut commands: Commands,
 ) {
     let tooltip_item = r!(tooltip_query.get(hover.entity));
+    let has_nested = tooltip_item
+        .nested
+        .is_some_and(|nested| !closing_query.contains(nested.0));
 
     // despawns occur at nested level
-    if tooltip_item.has_nested || tooltip_item.locked || !tooltip_item.debounced {
+    if has_nested || tooltip_item.locked || !tooltip_item.debounced {
         return;
     }
 
     if tooltip_item.relative_cursor.cursor_over {
         return;
     }
-    r!(commands.get_entity(hover.entity)).despawn();
+    commands.trigger(CloseTooltip {
+        entity: hover.entity,
+    });
 }
 
-/// When user has pressed the middle mouse button on a [`TooltipLink`].
-#[allow(clippy::too_many_arguments)]
-fn middle_mouse_spawn(
+/// When user has pressed the configured button on a [`TooltipLink`].
+fn press_spawn(
     mut press: On<Pointer<Press>>,
-    links_query: Query<AnyOf<(&TooltipTermLink, &TooltipTermLinkRecursive)>>,
-    existing_tooltips_query: Query<(Entity, &Tooltip)>,
-    window_query: Query<&Window>,
-    tooltips_map: Res<TooltipMap>,
-    tooltip_reference: Res<TooltipReference>,
     tooltip_configuration: Res<TooltipConfiguration>,
-    mut commands: Commands,
+    mut spawner: TooltipSpawner,
 ) {
-    // Stop tooltip lock being triggered
+    // Stop tooltip lock being triggered, even by buttons that do not open tooltips
     press.propagate(false);
-    if press.button != PointerButton::Middle {
+    let button = rq!(tooltip_configuration.activation_method.press_button());
+    if press.button != button {
         return;
     }
-    spawn_tooltip(
-        press.entity,
-        links_query,
-        existing_tooltips_query,
-        window_query,
-        tooltips_map,
-        tooltip_reference,
-        tooltip_configuration,
-        &mut commands,
-    );
+    spawner.spawn_at_location(press.entity, &press.pointer_location, press.hit.camera);
 }
 
-/// Common logic to spawn [`ToolTip`] should be called when activation method has been satisfied
-/// This also blocks tooltips from spawning if entity has already spawned one.
-#[allow(clippy::too_many_arguments)]
-fn spawn_tooltip(
-    term_entity: Entity,
-    links_query: Query<'_, '_, AnyOf<(&TooltipTermLink, &TooltipTermLinkRecursive)>>,
-    existing_tooltips_query: Query<(Entity, &Tooltip)>,
-    window_query: Query<'_, '_, &Window>,
-    tooltips_map: Res<'_, TooltipMap>,
-    tooltip_reference: Res<'_, TooltipReference>,
-    tooltip_configuration: Res<TooltipConfiguration>,
-    commands: &mut Commands<'_, '_>,
-) {
-    // Prevent the same entity having two existing tooltips spawned
-    for (_, tooltip) in existing_tooltips_query {
-        if tooltip.entity == term_entity {
-            return;
+/// The current [`Location`] of the pointer with the given id.
+fn pointer_location(
+    pointer_id: PointerId,
+    pointer_query: Query<(&PointerId, &PointerLocation)>,
+) -> Option<Location> {
+    pointer_query
+        .iter()
+        .find(|(id, _)| **id == pointer_id)
+        .and_then(|(_, pointer_location)| pointer_location.location().cloned())
+}
+
+/// The nodes of a [`Tooltip`] replaced when navigating in place.
+type TooltipContentFilter = Or<(With<TooltipTitleNode>, With<TooltipTextScroll>)>;
+
+/// Everything needed to spawn a [`Tooltip`], shared by each way a tooltip can be activated.
+#[derive(SystemParam)]
+pub(crate) struct TooltipSpawner<'w, 's> {
+    links_query:
+        Query<'w, 's, AnyOf<(&'static TooltipTermLink, &'static TooltipTermLinkRecursive)>>,
+    existing_tooltips_query: Query<'w, 's, (Entity, &'static Tooltip), Without<TooltipClosing>>,
+    nested_of_query: Query<'w, 's, &'static TooltipsNestedOf>,
+    nested_query: Query<'w, 's, &'static TooltipsNested>,
+    depth_query: Query<'w, 's, &'static mut TooltipDepth>,
+    locked_query: Query<'w, 's, (), With<TooltipLocked>>,
+    z_index_query: Query<'w, 's, &'static mut GlobalZIndex, OpenTooltipFilter>,
+    history_query: Query<'w, 's, &'static mut TooltipHistory>,
+    content_query: Query<'w, 's, (Entity, &'static ChildOf), TooltipContentFilter>,
+    ancestor_query: Query<'w, 's, &'static ChildOf>,
+    input_focus: Option<Res<'w, InputFocus>>,
+    camera_query: Query<'w, 's, &'static Camera>,
+    link_rect: LinkRectParam<'w, 's>,
+    ui_scale: Res<'w, UiScale>,
+    tooltips_map: Res<'w, TooltipMap>,
+    tooltip_reference: Res<'w, TooltipReference>,
+    tooltip_configuration: Res<'w, TooltipConfiguration>,
+    time: Res<'w, Time>,
+    commands: Commands<'w, 's>,
+}
+
+impl<'w, 's> TooltipSpawner<'w, 's> {
+    /// Spawns the tooltip at the pointers `location` rendered by `camera`,
+    /// so it ends up in the same window as the pointer that activated it.
+    pub(crate) fn spawn_at_location(
+        &mut self,
+        term_entity: Entity,
+        location: &Location,
+        camera: Entity,
+    ) -> Option<Entity> {
+        let camera_item = r!(self.camera_query.get(camera));
+        let (cursor_position, _) = r!(ui_space_position(location, camera_item, &self.ui_scale));
+        self.spawn(term_entity, cursor_position, camera)
+    }
+
+    /// Commands of the spawner, for changing the spawned tooltip.
+    pub(crate) fn commands(&mut self) -> &mut Commands<'w, 's> {
+        &mut self.commands
+    }
+
+    /// Spawns the tooltip next to the link, for when there is no pointer.
+    /// Tooltips placed at the pointer are anchored to the link instead so they do not cover it.
+    pub(crate) fn spawn_at_link(&mut self, term_entity: Entity, camera: Entity) -> Option<Entity> {
+        let link = r!(self.link_rect.link_logical_rect(term_entity, camera));
+        let nested = self
+            .links_query
+            .get(term_entity)
+            .is_ok_and(|(_, recursive)| recursive.is_some());
+        let tooltip = self.spawn(term_entity, link.center(), camera)?;
+        let placement = match (nested, &self.tooltip_configuration.nesting) {
+            // The link replaced the content of its own tooltip
+            (true, NestingMode::InPlace) => return Some(tooltip),
+            (true, NestingMode::Nested) => &self.tooltip_configuration.nested_placement,
+            (false, _) => &self.tooltip_configuration.placement,
+        };
+        if matches!(
+            placement,
+            TooltipPlacement::Cursor | TooltipPlacement::FollowCursor
+        ) {
+            r!(self.commands.get_entity(tooltip)).insert(TooltipPlacement::anchored());
         }
+        Some(tooltip)
+    }
+
+    /// Spawns the tooltip of `tooltip_term` next to `anchor`, which does not need to be a link.
+    pub(crate) fn spawn_term_at_anchor(
+        &mut self,
+        anchor: Entity,
+        tooltip_term: &str,
+        nested: Option<Entity>,
+        camera: Entity,
+    ) -> Option<Entity> {
+        let anchor_rect = r!(self.link_rect.link_logical_rect(anchor, camera));
+        self.spawn_term(
+            Some(anchor),
+            tooltip_term,
+            nested,
+            anchor_rect.center(),
+            camera,
+        )
     }
 
-    let link_item = r!(links_query.get(term_entity));
-    let (tooltip_term, nested) = match link_item {
-        // Guranteed to have at least one entity
-        (None, None) => {
-            error!("Bevy invariant failed");
-            return;
+    /// Common logic to spawn [`ToolTip`] should be called when activation method has been satisfied
+    /// This also blocks tooltips from spawning if entity has already spawned one.
+    ///
+    /// The tooltip is placed at `cursor_position` in the logical UI space of `camera`.
+    /// Links in the world are placed where the entity is projected to by `camera` instead.
+    pub(crate) fn spawn(
+        &mut self,
+        term_entity: Entity,
+        cursor_position: Vec2,
+        camera: Entity,
+    ) -> Option<Entity> {
+        // Prevent the same entity having two existing tooltips spawned
+        for (_, tooltip) in &self.existing_tooltips_query {
+            if tooltip.link == Some(term_entity) {
+                return None;
+            }
         }
-        (None, Some(s)) => (s.linked_string.clone(), Some(s.parent_entity)),
-        (Some(s), None) => (s.linked_string.clone(), None),
-        // Shouldn't have both types of links could be caused by user if they tried hard enough
-        (Some(_), Some(_)) => {
-            error!("Nested tooltips has a bug");
-            return;
+
+        let link_item = r!(self.links_query.get(term_entity));
+        let (tooltip_term, nested) = match link_item {
+            // Guranteed to have at least one entity
+            (None, None) => {
+                error!("Bevy invariant failed");
+                return None;
+            }
+            (None, Some(s)) => (s.linked_string.clone(), Some(s.parent_entity)),
+            (Some(s), None) => (s.linked_string.clone(), None),
+            // Shouldn't have both types of links could be caused by user if they tried hard enough
+            (Some(_), Some(_)) => {
+                error!("Nested tooltips has a bug");
+                return None;
+            }
+        };
+        self.spawn_term(
+            Some(term_entity),
+            &tooltip_term,
+            nested,
+            cursor_position,
+            camera,
+        )
+    }
+
+    /// Shows `tooltip_term` in place of the content of `tooltip`, adding it to the [`TooltipHistory`].
+    /// Nothing changes if the term is already shown.
+    pub(crate) fn navigate(&mut self, tooltip: Entity, tooltip_term: &str) -> Option<Entity> {
+        r!(self.tooltips_map.get(tooltip_term));
+        let mut history = r!(self.history_query.get_mut(tooltip));
+        if history.current() == tooltip_term {
+            return Some(tooltip);
         }
-    };
+        history.push(tooltip_term);
+        self.replace_content(tooltip, tooltip_term)
+    }
+
+    /// Shows the previous term in the [`TooltipHistory`] of `tooltip`.
+    pub(crate) fn navigate_back(&mut self, tooltip: Entity) -> Option<Entity> {
+        let mut history = r!(self.history_query.get_mut(tooltip));
+        let tooltip_term = rq!(history.back()).to_string();
+        self.replace_content(tooltip, &tooltip_term)
+    }
+
+    /// Shows the next term in the [`TooltipHistory`] of `tooltip`.
+    pub(crate) fn navigate_forward(&mut self, tooltip: Entity) -> Option<Entity> {
+        let mut history = r!(self.history_query.get_mut(tooltip));
+        let tooltip_term = rq!(history.forward()).to_string();
+        self.replace_content(tooltip, &tooltip_term)
+    }
 
-    // Despawn other top level `ToolTip`s
-    let zindex = match nested {
-        None => {
-            for (entity, _) in existing_tooltips_query {
-                c!(commands.get_entity(entity)).try_despawn();
+    /// Despawns the title and text of `tooltip` and spawns those of `tooltip_term` in their place.
+    /// Focus on the despawned content is moved to the new content with [`TooltipRefocus`].
+    fn replace_content(&mut self, tooltip: Entity, tooltip_term: &str) -> Option<Entity> {
+        let tooltip_data = r!(self.tooltips_map.get(tooltip_term));
+        for (entity, child_of) in &self.content_query {
+            if child_of.parent() == tooltip {
+                c!(self.commands.get_entity(entity)).try_despawn();
             }
-            GlobalZIndex(tooltip_configuration.starting_z_index)
         }
-        Some(_) => GlobalZIndex(
-            existing_tooltips_query.count() as i32 + tooltip_configuration.starting_z_index,
-        ),
-    };
+        spawn_content(&mut self.commands, tooltip, tooltip_data);
+        let focused = self.input_focus.as_ref().and_then(|focus| focus.get());
+        if focused.is_some_and(|focused| {
+            focused != tooltip && self.ancestor_query.root_ancestor(focused) == tooltip
+        }) {
+            r!(self.commands.get_entity(tooltip)).insert(TooltipRefocus);
+        }
 
-    let tooltip_data = r!(tooltips_map.get(&tooltip_term));
-    let design_node = position_tooltip(window_query, tooltip_reference);
+        self.commands.trigger(TooltipSpawned { entity: tooltip });
+        Some(tooltip)
+    }
 
-    let mut tooltip_commands = commands.spawn((
-        design_node,
-        Tooltip {
-            entity: term_entity,
-        },
-        TooltipWaitForHover {
-            timer: Timer::new(
-                tooltip_configuration.interaction_wait_for_time,
-                TimerMode::Once,
-            ),
-        },
-        zindex,
-        Pickable {
-            should_block_lower: true,
-            is_hoverable: true,
-        },
-        children![(
+    /// Closes every tooltip except locked top level ones and the tooltips nested under them.
+    /// When there are more than `max_pinned` locked top level tooltips the oldest are closed too.
+    /// Returns the tooltips kept open, their z indices renumbered from the starting z index.
+    fn close_unpinned(&mut self) -> Vec<Entity> {
+        let mut pinned: Vec<(Entity, Duration)> = self
+            .existing_tooltips_query
+            .iter()
+            .filter(|(entity, _)| {
+                !self.nested_of_query.contains(*entity) && self.locked_query.contains(*entity)
+            })
+            .map(|(entity, tooltip)| (entity, tooltip.opened))
+            .collect();
+        pinned.sort_by_key(|(_, opened)| std::cmp::Reverse(*opened));
+        pinned.truncate(self.tooltip_configuration.max_pinned);
+
+        let mut kept = Vec::new();
+        for (entity, _) in &self.existing_tooltips_query {
+            let root = self
+                .nested_of_query
+                .iter_ancestors(entity)
+                .last()
+                .unwrap_or(entity);
+            if pinned.iter().any(|(pinned, _)| *pinned == root) {
+                kept.push(entity);
+            } else {
+                self.commands.trigger(CloseTooltip { entity });
+            }
+        }
+
+        // Renumber in the same order so z indices do not grow while tooltips stay pinned
+        kept.sort_by_key(|entity| {
+            self.z_index_query
+                .get(*entity)
+                .map_or(i32::MIN, |z_index| z_index.0)
+        });
+        for (z_index, entity) in (self.tooltip_configuration.starting_z_index..).zip(&kept) {
+            let mut tooltip_z_index = c!(self.z_index_query.get_mut(*entity));
+            tooltip_z_index.0 = z_index;
+        }
+        kept
+    }
+
+    /// The z index above every one of `tooltips`, or the starting z index if there are none.
+    fn z_index_above(&self, tooltips: impl IntoIterator<Item = Entity>) -> GlobalZIndex {
+        self.z_index_query
+            .iter_many(tooltips)
+            .map(|z_index| z_index.0 + 1)
+            .max()
+            .map_or(
+                GlobalZIndex(self.tooltip_configuration.starting_z_index),
+                GlobalZIndex,
+            )
+    }
+
+    /// Applies the [`DepthLimit`] to a tooltip opened by `link` that would be nested under `nested`.
+    /// Returns the link to open from, the tooltip to nest under and the depth,
+    /// none if the tooltip should not be spawned.
+    fn limit_depth(
+        &mut self,
+        link: Option<Entity>,
+        nested: Option<Entity>,
+    ) -> Option<(Option<Entity>, Option<Entity>, usize)> {
+        let Some(parent) = nested else {
+            return Some((link, None, 0));
+        };
+        let depth = self.depth_query.get(parent).map_or(1, |depth| depth.0 + 1);
+        if self
+            .tooltip_configuration
+            .max_depth
+            .is_none_or(|max_depth| depth <= max_depth)
+        {
+            return Some((link, nested, depth));
+        }
+
+        // The link is inside the closed tooltip, open from the link that opened it instead
+        let replace_parent = |spawner: &mut Self| {
+            let (_, parent_tooltip) = r!(spawner.existing_tooltips_query.get(parent));
+            let parent_link = parent_tooltip.link();
+            spawner.commands.trigger(CloseTooltip { entity: parent });
+            let grandparent = spawner
+                .nested_of_query
+                .get(parent)
+                .ok()
+                .map(|nested_of| nested_of.0);
+            Some((parent_link, grandparent, depth - 1))
+        };
+        match self.tooltip_configuration.depth_limit {
+            DepthLimit::Ignore => None,
+            DepthLimit::ReplaceDeepest => replace_parent(self),
+            DepthLimit::CloseOldest => {
+                // Ancestors already closing are not part of the chain anymore
+                let oldest = r!(std::iter::once(parent)
+                    .chain(self.nested_of_query.iter_ancestors(parent))
+                    .filter(|entity| self.existing_tooltips_query.contains(*entity))
+                    .last());
+                if oldest == parent {
+                    return replace_parent(self);
+                }
+                self.commands.trigger(CloseTooltip { entity: oldest });
+                let descendants: Vec<Entity> = self.nested_query.iter_descendants(oldest).collect();
+                for entity in descendants {
+                    let mut descendant_depth = c!(self.depth_query.get_mut(entity));
+                    descendant_depth.0 = descendant_depth.0.saturating_sub(1);
+                }
+                Some((link, nested, depth - 1))
+            }
+        }
+    }
+
+    /// Spawns the tooltip of `tooltip_term` opened by `link`, nested under the `nested`
+    /// tooltip if there is one.
+    /// Unlike [`TooltipSpawner::spawn`] the link does not need to be a link component, and there
+    /// is none for tooltips opened at a position.
+    pub(crate) fn spawn_term(
+        &mut self,
+        link: Option<Entity>,
+        tooltip_term: &str,
+        nested: Option<Entity>,
+        cursor_position: Vec2,
+        camera: Entity,
+    ) -> Option<Entity> {
+        if let (NestingMode::InPlace, Some(parent)) = (&self.tooltip_configuration.nesting, nested)
+        {
+            return self.navigate(parent, tooltip_term);
+        }
+        let (link, nested, depth) = rq!(self.limit_depth(link, nested));
+
+        // Close other top level `ToolTip`s unless pinned, the new tooltip goes above the ones left
+        let zindex = match nested {
+            None => {
+                let kept = self.close_unpinned();
+                self.z_index_above(kept)
+            }
+            Some(_) => {
+                let open: Vec<Entity> = self
+                    .existing_tooltips_query
+                    .iter()
+                    .map(|(entity, _)| entity)
+                    .collect();
+                self.z_index_above(open)
+            }
+        };
+
+        let placement = match nested {
+            None => self.tooltip_configuration.placement.clone(),
+            Some(_) => self.tooltip_configuration.nested_placement.clone(),
+        };
+        let wait_for_time = self
+            .tooltip_configuration
+            .nested_interaction_wait_for_time
+            .time(self.tooltip_configuration.interaction_wait_for_time, depth);
+
+        let tooltip_data = r!(self.tooltips_map.get(tooltip_term));
+        let camera_item = r!(self.camera_query.get(camera));
+        let viewport_size = r!(camera_item.logical_viewport_size()) / self.ui_scale.0;
+        // Links in the world open where the entity is rather than where it was picked
+        let cursor_position = link
+            .and_then(|link| self.link_rect.world_position(link, camera))
+            .unwrap_or(cursor_position);
+        let design_node = position_tooltip(cursor_position, viewport_size, &self.tooltip_reference);
+
+        let mut tooltip_commands = self.commands.spawn((
+            design_node,
+            Tooltip {
+                link,
+                opened: self.time.elapsed(),
+            },
+            UiTargetCamera(camera),
+            placement,
+            TooltipCursorPosition(cursor_position),
+            TooltipDepth(depth),
+            TooltipPlacing::default(),
+            Visibility::Hidden,
+            TooltipWaitForHover {
+                timer: Timer::new(wait_for_time, TimerMode::Once),
+            },
+            zindex,
+            Pickable {
+                should_block_lower: true,
+                is_hoverable: true,
+            },
+        ));
+        if let Some(nested) = nested {
+            tooltip_commands.insert(TooltipsNestedOf(nested));
+        }
+        if let NestingMode::InPlace = self.tooltip_configuration.nesting {
+            tooltip_commands.insert(TooltipHistory::new(tooltip_term));
+        }
+        if self.tooltip_configuration.caret {
+            tooltip_commands.with_child((
+                TooltipCaret {
+                    side: None,
+                    offset: 0.0,
+                },
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..Default::default()
+                },
+                Visibility::Hidden,
+                Pickable::IGNORE,
+            ));
+        }
+        if self.tooltip_configuration.opening_time.is_zero() {
+            tooltip_commands.insert(TooltipOpen);
+        } else {
+            tooltip_commands.insert(TooltipOpening::new(self.tooltip_configuration.opening_time));
+        }
+        let tooltip_id = tooltip_commands.id();
+        spawn_content(&mut self.commands, tooltip_id, tooltip_data);
+
+        self.commands.trigger(TooltipSpawned { entity: tooltip_id });
+        Some(tooltip_id)
+    }
+}
+
+/// Spawns the title and text of `tooltip_data` as the first children of `tooltip`.
+fn spawn_content(commands: &mut Commands, tooltip: Entity, tooltip_data: &TooltipsData) {
+    let title_node = commands
+        .spawn((
             TooltipTitleNode,
             Node {
                 display: Display::Flex,
                 ..Default::default()
             },
-            children![(TooltipTitleText, Text::new(tooltip_data.title.clone()))]
-        )],
-    ));
-    if let Some(nested) = nested {
-        tooltip_commands.insert(TooltipsNestedOf(nested));
-    }
-    tooltip_commands.with_children(|parent| {
-        let parent_entity = parent.target_entity();
-        parent
-            .spawn((
-                TooltipTextNode,
-                Node {
-                    display: Display::Flex,
-                    width: Val::Percent(100.),
-                    ..Default::default()
-                },
-                Text::new(""),
-            ))
-            .with_children(|text| {
-                for c in &tooltip_data.content {
-                    match c.clone() {
-                        TooltipsContent::String(s) => {
-                            text.spawn((TooltipStringText, TextSpan::new(s)));
-                        }
-                        TooltipsContent::Term(s) => {
-                            text.spawn((
-                                TooltipTermLinkRecursive::new(parent_entity, s.clone()),
-                                TextSpan::new(s),
-                            ));
-                        }
-                        TooltipsContent::Highlight(s) => {
-                            text.spawn((TooltipHighlightLink(s.clone()), TextSpan::new(s)));
-                        }
+            children![(TooltipTitleText, Text::new(tooltip_data.title.clone()))],
+        ))
+        .id();
+    let text_node = commands
+        .spawn((
+            TooltipTextNode,
+            Node {
+                display: Display::Flex,
+                width: Val::Percent(100.),
+                ..Default::default()
+            },
+            Text::new(""),
+        ))
+        .with_children(|text| {
+            for c in &tooltip_data.content {
+                match c.clone() {
+                    TooltipsContent::String(s) => {
+                        text.spawn((TooltipStringText, TextSpan::new(s)));
+                    }
+                    TooltipsContent::Term(s) => {
+                        text.spawn((
+                            TooltipTermLinkRecursive::new(tooltip, s.clone()),
+                            TextSpan::new(s),
+                        ));
+                    }
+                    TooltipsContent::Highlight(s) => {
+                        text.spawn((TooltipHighlightLink(s.clone()), TextSpan::new(s)));
                     }
                 }
-            });
-    });
-    let tooltip_id = tooltip_commands.id();
+            }
+        })
+        .id();
+    // The text node scrolls inside this node, as nodes only scroll their children
+    let text_scroll = commands
+        .spawn((
+            TooltipTextScroll,
+            Node {
+                min_height: Val::Px(0.),
+                overflow: Overflow::scroll_y(),
+                ..Default::default()
+            },
+        ))
+        .add_child(text_node)
+        .id();
+    commands
+        .entity(tooltip)
+        .insert_children(0, &[title_node, text_scroll]);
+}
 
-    commands.trigger(TooltipSpawned { entity: tooltip_id });
+/// The pointers position and the viewport size in the logical UI space of the camera,
+/// so they respect the cameras viewport and [`UiScale`].
+fn ui_space_position(
+    location: &Location,
+    camera: &Camera,
+    ui_scale: &UiScale,
+) -> Option<(Vec2, Vec2)> {
+    let viewport = camera.logical_viewport_rect()?;
+    Some((
+        (location.position - viewport.min) / ui_scale.0,
+        viewport.size() / ui_scale.0,
+    ))
 }
 
-/// Poistions the [`ToolTip`] relative to the cursor.
+/// Poistions the [`ToolTip`] relative to the pointer.
+/// This is the position before the size of the tooltip is known,
+/// once laid out it is corrected to fit on screen.
 fn position_tooltip(
-    window_query: Query<'_, '_, &Window>,
-    tooltip_reference: Res<'_, TooltipReference>,
+    cursor_position: Vec2,
+    viewport_size: Vec2,
+    tooltip_reference: &TooltipReference,
 ) -> Node {
     let mut design_node = tooltip_reference.tooltip_node.clone();
-    let window = r!(window_query.single());
-    let cursor_position = r!(window.cursor_position());
-
-    let window_size = window.size();
-    let half_window_size = window_size / 2.0;
-    let offset = 8.0;
-    let (left, right) = if cursor_position.x > half_window_size.x {
+    let half_viewport_size = viewport_size / 2.0;
+    let offset = TOOLTIP_OFFSET;
+    let (left, right) = if cursor_position.x > half_viewport_size.x {
         (
             Val::Auto,
-            Val::Px(window_size.x - cursor_position.x + offset),
+            Val::Px(viewport_size.x - cursor_position.x + offset),
         )
     } else {
         (Val::Px(cursor_position.x + offset), Val::Auto)
     };
-    let (top, bottom) = if cursor_position.y > half_window_size.y {
+    let (top, bottom) = if cursor_position.y > half_viewport_size.y {
         (
             Val::Auto,
-            Val::Px(window_size.y - cursor_position.y + offset),
+            Val::Px(viewport_size.y - cursor_position.y + offset),
         )
     } else {
         (Val::Px(cursor_position.y + offset), Val::Auto)
@@ -805,13 +1709,14 @@ struct LockTooltipQuery {
     locked: Has<TooltipLocked>,
 }
 
-/// When user presses middle mouse button add or remove [`TooltipLocked`].
+/// When user presses the lock button add or remove [`TooltipLocked`].
 fn toggle_lock(
     press: On<Pointer<Press>>,
     tooltip_query: Query<LockTooltipQuery>,
+    tooltip_configuration: Res<TooltipConfiguration>,
     mut commands: Commands,
 ) {
-    if press.button == PointerButton::Middle {
+    if press.button == tooltip_configuration.lock_button {
         let tooltip_item = r!(tooltip_query.get(press.entity));
         if tooltip_item.locked {
             r!(commands.get_entity(press.entity)).remove::<TooltipLocked>();
@@ -820,3 +1725,281 @@ fn toggle_lock(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    const TOP_LEVEL: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn top_level_time_at_depth_zero() {
+        let timings = [
+            NestedTiming::Same,
+            NestedTiming::PerDepth(vec![Duration::from_millis(100)]),
+            NestedTiming::Scaled {
+                factor: 0.5,
+                min: Duration::ZERO,
+            },
+        ];
+        for timing in timings {
+            assert_eq!(timing.time(TOP_LEVEL, 0), TOP_LEVEL);
+        }
+    }
+
+    #[test]
+    fn per_depth_uses_last_time_for_deeper() {
+        let timing =
+            NestedTiming::PerDepth(vec![Duration::from_millis(500), Duration::from_millis(200)]);
+        assert_eq!(timing.time(TOP_LEVEL, 1), Duration::from_millis(500));
+        assert_eq!(timing.time(TOP_LEVEL, 2), Duration::from_millis(200));
+        assert_eq!(timing.time(TOP_LEVEL, 5), Duration::from_millis(200));
+        assert_eq!(
+            NestedTiming::PerDepth(Vec::new()).time(TOP_LEVEL, 3),
+            TOP_LEVEL
+        );
+    }
+
+    #[test]
+    fn scaled_by_depth_with_min() {
+        let timing = NestedTiming::Scaled {
+            factor: 0.5,
+            min: Duration::from_millis(200),
+        };
+        assert_eq!(timing.time(TOP_LEVEL, 1), Duration::from_millis(500));
+        assert_eq!(timing.time(TOP_LEVEL, 2), Duration::from_millis(250));
+        assert_eq!(timing.time(TOP_LEVEL, 3), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn scaled_saturates_instead_of_panicking() {
+        let min = Duration::from_millis(200);
+        let growing = NestedTiming::Scaled { factor: 2.0, min };
+        assert_eq!(growing.time(TOP_LEVEL, 200), Duration::MAX);
+        assert_eq!(growing.time(TOP_LEVEL, usize::MAX), Duration::MAX);
+
+        for factor in [-2.0, f32::NAN] {
+            let timing = NestedTiming::Scaled { factor, min };
+            assert_eq!(timing.time(TOP_LEVEL, 3), min);
+        }
+    }
+
+    fn link_timer(pointer_id: PointerId) -> TooltipLinkTimer {
+        TooltipLinkTimer {
+            timer: Timer::from_seconds(0.1, TimerMode::Once),
+            pointer_id,
+            camera: Entity::PLACEHOLDER,
+            moved: 0.0,
+        }
+    }
+
+    #[test]
+    fn aiming_only_holds_links_of_its_pointer() {
+        let mut world = World::new();
+        world.insert_resource(TooltipConfiguration::default());
+        world.insert_resource(Time::<()>::default());
+        world.spawn((
+            Tooltip {
+                link: None,
+                opened: Duration::ZERO,
+            },
+            TooltipAiming {
+                pointer_id: PointerId::Mouse,
+            },
+        ));
+        let aimed_past = world
+            .spawn((TooltipTermLink::new("a"), link_timer(PointerId::Mouse)))
+            .id();
+        let touched = world
+            .spawn((TooltipTermLink::new("b"), link_timer(PointerId::Touch(0))))
+            .id();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(200));
+        world.run_system_once(tick_timers).unwrap();
+
+        let timer = world.get::<TooltipLinkTimer>(aimed_past).unwrap();
+        assert_eq!(timer.timer.elapsed(), Duration::ZERO);
+        assert!(world.get::<TooltipLinkTimer>(touched).is_none());
+    }
+
+    /// Resources needed by the [`TooltipSpawner`], closed tooltips are despawned straight away.
+    fn spawner_resources(world: &mut World, configuration: TooltipConfiguration) {
+        world.insert_resource(configuration);
+        world.insert_resource(TooltipMap {
+            map: Default::default(),
+        });
+        world.insert_resource(TooltipReference::default());
+        world.insert_resource(UiScale::default());
+        world.insert_resource(Time::<()>::default());
+        world.add_observer(|close: On<CloseTooltip>, mut commands: Commands| {
+            commands.entity(close.entity).despawn();
+        });
+    }
+
+    /// A chain of tooltips nested to depth 2, returning the tooltips and the links that opened them.
+    fn nested_chain(world: &mut World, depth_limit: DepthLimit) -> ([Entity; 3], [Entity; 3]) {
+        spawner_resources(
+            world,
+            TooltipConfiguration {
+                max_depth: Some(2),
+                depth_limit,
+                ..Default::default()
+            },
+        );
+
+        let links = [
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+        ];
+        let mut tooltips = Vec::new();
+        for (depth, link) in links.iter().enumerate() {
+            let mut tooltip = world.spawn((
+                Tooltip {
+                    link: Some(*link),
+                    opened: Duration::ZERO,
+                },
+                TooltipDepth(depth),
+            ));
+            if let Some(parent) = tooltips.last() {
+                tooltip.insert(TooltipsNestedOf(*parent));
+            }
+            tooltips.push(tooltip.id());
+        }
+        (tooltips.try_into().unwrap(), links)
+    }
+
+    fn limit_depth(
+        world: &mut World,
+        link: Entity,
+        nested: Entity,
+    ) -> Option<(Option<Entity>, Option<Entity>, usize)> {
+        world
+            .run_system_once(move |mut spawner: TooltipSpawner| {
+                spawner.limit_depth(Some(link), Some(nested))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn replace_deepest_opens_from_link_of_replaced_tooltip() {
+        let mut world = World::new();
+        let ([root, middle, deepest], [_, _, deepest_link]) =
+            nested_chain(&mut world, DepthLimit::ReplaceDeepest);
+        let link = world.spawn(ChildOf(deepest)).id();
+
+        assert_eq!(
+            limit_depth(&mut world, link, deepest),
+            Some((Some(deepest_link), Some(middle), 2))
+        );
+        assert!(world.get_entity(deepest).is_err());
+        assert!(world.get_entity(root).is_ok());
+    }
+
+    #[test]
+    fn close_oldest_moves_the_chain_up() {
+        let mut world = World::new();
+        let ([root, middle, deepest], _) = nested_chain(&mut world, DepthLimit::CloseOldest);
+        let link = world.spawn(ChildOf(deepest)).id();
+
+        assert_eq!(
+            limit_depth(&mut world, link, deepest),
+            Some((Some(link), Some(deepest), 2))
+        );
+        assert!(world.get_entity(root).is_err());
+        assert_eq!(world.get::<TooltipDepth>(middle).unwrap().0, 0);
+        assert_eq!(world.get::<TooltipDepth>(deepest).unwrap().0, 1);
+    }
+
+    #[test]
+    fn depth_within_limit_is_unchanged() {
+        for depth_limit in [DepthLimit::ReplaceDeepest, DepthLimit::CloseOldest] {
+            let mut world = World::new();
+            let ([root, middle, _], _) = nested_chain(&mut world, depth_limit);
+            let link = world.spawn(ChildOf(middle)).id();
+
+            assert_eq!(
+                limit_depth(&mut world, link, middle),
+                Some((Some(link), Some(middle), 2))
+            );
+            assert!(world.get_entity(root).is_ok());
+        }
+    }
+
+    #[test]
+    fn kept_tooltips_are_renumbered_from_starting_z_index() {
+        let mut world = World::new();
+        spawner_resources(&mut world, TooltipConfiguration::default());
+        let tooltip = |z_index| {
+            (
+                Tooltip {
+                    link: None,
+                    opened: Duration::ZERO,
+                },
+                GlobalZIndex(z_index),
+            )
+        };
+        let upper = world.spawn((tooltip(40), TooltipLocked)).id();
+        let lower = world.spawn((tooltip(20), TooltipLocked)).id();
+        let unlocked = world.spawn(tooltip(50)).id();
+
+        let z_index = world
+            .run_system_once(|mut spawner: TooltipSpawner| {
+                let kept = spawner.close_unpinned();
+                spawner.z_index_above(kept)
+            })
+            .unwrap();
+
+        let starting_z_index = TooltipConfiguration::default().starting_z_index;
+        assert_eq!(
+            world.get::<GlobalZIndex>(lower).unwrap().0,
+            starting_z_index
+        );
+        assert_eq!(
+            world.get::<GlobalZIndex>(upper).unwrap().0,
+            starting_z_index + 1
+        );
+        assert_eq!(z_index.0, starting_z_index + 2);
+        assert!(world.get_entity(unlocked).is_err());
+    }
+
+    #[test]
+    fn navigate_to_current_term_keeps_content() {
+        let mut world = World::new();
+        spawner_resources(
+            &mut world,
+            TooltipConfiguration {
+                nesting: NestingMode::InPlace,
+                ..Default::default()
+            },
+        );
+        world
+            .resource_mut::<TooltipMap>()
+            .insert("term".into(), TooltipsData::new("Term", Vec::new()));
+        let tooltip = world
+            .spawn((
+                Tooltip {
+                    link: None,
+                    opened: Duration::ZERO,
+                },
+                TooltipHistory::new("term"),
+            ))
+            .id();
+
+        world
+            .run_system_once(move |mut spawner: TooltipSpawner| {
+                spawner.navigate(tooltip, "term");
+            })
+            .unwrap();
+
+        assert_eq!(
+            world.get::<TooltipHistory>(tooltip).unwrap().terms().len(),
+            1
+        );
+        assert!(world.get::<Children>(tooltip).is_none());
+    }
+}
@@ -0,0 +1,138 @@
+//! Tooltips go through opening, open and closing phases so they can be animated.
+//! Observe these components being added to start fades or scaling, their durations are set in
+//! [`crate::TooltipConfiguration`].
+//!
+//! A closing tooltip stays alive but can not be interacted with, it is despawned once its closing
+//! phase is over.
+
+use std::time::Duration;
+
+use bevy_app::{Plugin, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::Children,
+    observer::On,
+    query::{Has, With},
+    system::{Commands, Query, Res},
+};
+use bevy_picking::Pickable;
+use bevy_time::{Time, Timer, TimerMode};
+use tiny_bail::prelude::*;
+
+use crate::{Tooltip, TooltipConfiguration, TooltipLinkTimer, TooltipWaitForHover};
+
+pub(crate) struct LifecyclePlugin;
+
+impl Plugin for LifecyclePlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(Update, (tick_opening, tick_closing))
+            .add_observer(start_closing);
+    }
+}
+
+/// The [`crate::Tooltip`] has just been spawned and is in its opening phase.
+/// Replaced with [`TooltipOpen`] once the opening time is over.
+#[derive(Debug, Component)]
+pub struct TooltipOpening {
+    timer: Timer,
+}
+
+impl TooltipOpening {
+    pub(crate) fn new(time: Duration) -> Self {
+        Self {
+            timer: Timer::new(time, TimerMode::Once),
+        }
+    }
+
+    /// How far through the opening phase the tooltip is, from 0 to 1.
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
+}
+
+/// The [`crate::Tooltip`] has finished opening.
+#[derive(Debug, Component)]
+pub struct TooltipOpen;
+
+/// The [`crate::Tooltip`] is closing, it ignores the pointer and is despawned
+/// once the closing time is over.
+#[derive(Debug, Component)]
+pub struct TooltipClosing {
+    timer: Timer,
+}
+
+impl TooltipClosing {
+    /// How far through the closing phase the tooltip is, from 0 to 1.
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
+}
+
+/// Starts the closing phase of a [`crate::Tooltip`], every way a tooltip is closed goes through this.
+#[derive(Debug, EntityEvent)]
+pub(crate) struct CloseTooltip {
+    pub(crate) entity: Entity,
+}
+
+fn tick_opening(
+    mut opening_query: Query<(Entity, &mut TooltipOpening)>,
+    time_res: Res<Time>,
+    mut commands: Commands,
+) {
+    for (entity, mut opening) in &mut opening_query {
+        opening.timer.tick(time_res.delta());
+        if opening.timer.is_finished() {
+            c!(commands.get_entity(entity))
+                .remove::<TooltipOpening>()
+                .insert(TooltipOpen);
+        }
+    }
+}
+
+fn tick_closing(
+    mut closing_query: Query<(Entity, &mut TooltipClosing)>,
+    time_res: Res<Time>,
+    mut commands: Commands,
+) {
+    for (entity, mut closing) in &mut closing_query {
+        closing.timer.tick(time_res.delta());
+        if closing.timer.is_finished() {
+            c!(commands.get_entity(entity)).try_despawn();
+        }
+    }
+}
+
+/// Despawns the tooltip straight away if there is no closing time, otherwise stops it
+/// and its links from being interacted with until it is despawned.
+/// Children with their own [`Pickable`] keep it.
+fn start_closing(
+    close: On<CloseTooltip>,
+    tooltip_query: Query<Has<TooltipClosing>, With<Tooltip>>,
+    children_query: Query<&Children>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut commands: Commands,
+) {
+    let closing = rq!(tooltip_query.get(close.entity));
+    if closing {
+        return;
+    }
+    if tooltip_configuration.closing_time.is_zero() {
+        r!(commands.get_entity(close.entity)).try_despawn();
+        return;
+    }
+    r!(commands.get_entity(close.entity))
+        .remove::<(TooltipOpening, TooltipOpen, TooltipWaitForHover)>()
+        .insert((
+            TooltipClosing {
+                timer: Timer::new(tooltip_configuration.closing_time, TimerMode::Once),
+            },
+            Pickable::IGNORE,
+        ));
+    for child in children_query.iter_descendants(close.entity) {
+        c!(commands.get_entity(child))
+            .try_insert_if_new(Pickable::IGNORE)
+            .try_remove::<TooltipLinkTimer>();
+    }
+}
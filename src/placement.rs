@@ -0,0 +1,888 @@
+//! Where a [`crate::Tooltip`] is placed once spawned.
+//! Placement that depends on the size of the tooltip is done after layout.
+
+use bevy_app::{Plugin, PostUpdate};
+use bevy_camera::{Camera, visibility::Visibility};
+use bevy_ecs::{
+    change_detection::{DetectChangesMut, Mut},
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    observer::On,
+    query::{QueryData, With, Without},
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, SystemParam},
+};
+use bevy_math::{Rect, Vec2};
+use bevy_picking::{
+    events::{Drag, DragEnd, Move, Pointer},
+    pointer::PointerButton,
+};
+use bevy_text::{ComputedTextBlock, TextLayoutInfo};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{
+    ComputedNode, ComputedUiRenderTargetInfo, Node, UiGlobalTransform, UiScale, UiSystems,
+    UiTargetCamera, Val,
+};
+use tiny_bail::prelude::*;
+
+use crate::{
+    ToolTipDebounced, Tooltip, TooltipsNestedOf,
+    events::{TooltipDragged, TooltipDropped, TooltipLocked, TooltipMoved},
+    layout::TooltipTitleNode,
+    lifecycle::CloseTooltip,
+    ui_space_position,
+};
+
+/// Distance in logical pixels between a [`crate::Tooltip`] and what it is placed next to.
+pub(crate) const TOOLTIP_OFFSET: f32 = 8.0;
+
+pub(crate) struct PlacementPlugin;
+
+impl Plugin for PlacementPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(PostUpdate, place_tooltips.after(UiSystems::Layout))
+            .add_observer(follow_cursor)
+            .add_observer(drag_tooltip)
+            .add_observer(drop_tooltip);
+    }
+}
+
+/// A side of the entity a [`crate::Tooltip`] is placed next to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooltipSide {
+    Above,
+    Below,
+    Left,
+    Right,
+}
+
+/// How a [`crate::Tooltip`] is placed, set in [`crate::TooltipConfiguration`].
+/// Each spawned tooltip keeps a copy of the placement it was spawned with.
+#[derive(Debug, Clone, Component, Default)]
+pub enum TooltipPlacement {
+    /// Next to the pointer when the tooltip was activated, on the side with the most room.
+    #[default]
+    Cursor,
+    /// Next to the link that spawned the tooltip, so the same link always opens in the same spot.
+    /// The first of `sides` with room for the tooltip is used, when none have room
+    /// the side with the most room is used.
+    Anchored { sides: Vec<TooltipSide> },
+    /// Beside the parent [`crate::Tooltip`] like a submenu, lined up with the link that spawned it.
+    /// Sides are tried right, left, below then above and the first that does not cover any
+    /// tooltip it is nested under is used.
+    /// Top level tooltips have no parent and are placed as [`TooltipPlacement::Cursor`].
+    Cascade,
+    /// Next to the pointer like [`TooltipPlacement::Cursor`], but trails the pointer while it
+    /// moves over the link that spawned the tooltip.
+    /// The tooltip stops following once it is debounced or locked.
+    FollowCursor,
+    /// Next to a link in the world, such as a sprite or mesh, following it every frame as it or
+    /// the camera moves.
+    /// The tooltip is closed when the link goes off screen or is despawned.
+    /// Links that are UI nodes or text are placed as [`TooltipPlacement::Cursor`].
+    Tracking,
+}
+
+impl TooltipPlacement {
+    /// Anchored to the link preferring below, then above, right and left.
+    pub fn anchored() -> Self {
+        TooltipPlacement::Anchored {
+            sides: vec![
+                TooltipSide::Below,
+                TooltipSide::Above,
+                TooltipSide::Right,
+                TooltipSide::Left,
+            ],
+        }
+    }
+}
+
+/// Child of a [`crate::Tooltip`] that points from its edge to the link that spawned it.
+/// Only spawned when [`crate::TooltipConfiguration::caret`] is set.
+/// The caret is positioned just outside `side` of the tooltip with its left or top edge `offset`
+/// along that side, its size and look are up to you.
+#[derive(Debug, Component, Clone, Copy, PartialEq)]
+pub struct TooltipCaret {
+    pub(crate) side: Option<TooltipSide>,
+    pub(crate) offset: f32,
+}
+
+impl TooltipCaret {
+    /// The side of the tooltip facing the link, none if the tooltip covers the link.
+    pub fn side(&self) -> Option<TooltipSide> {
+        self.side
+    }
+
+    /// Distance in logical pixels from the left or top of the tooltip, along [`Self::side`],
+    /// to the point closest to the centre of the link.
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+}
+
+/// Marker for a [`crate::Tooltip`] that is hidden until it has been laid out in its first position.
+#[derive(Debug, Component, Default)]
+pub(crate) struct TooltipPlacing {
+    /// A position using the size of the tooltip has been set, layout uses it in the next frame.
+    placed: bool,
+}
+
+/// Where the pointer was in logical UI space when the [`crate::Tooltip`] was activated.
+/// Kept up to date while the pointer moves over the link for [`TooltipPlacement::FollowCursor`].
+#[derive(Debug, Component)]
+pub(crate) struct TooltipCursorPosition(pub(crate) Vec2);
+
+#[derive(SystemParam)]
+/// Finds where link entities are in logical UI space.
+/// Links can be UI nodes, text spans or entities in the world such as sprites and meshes.
+pub(crate) struct LinkRectParam<'w, 's> {
+    node_query: Query<'w, 's, (&'static ComputedNode, &'static UiGlobalTransform)>,
+    text_block_query: Query<
+        'w,
+        's,
+        (
+            &'static ComputedTextBlock,
+            &'static TextLayoutInfo,
+            &'static ComputedNode,
+            &'static UiGlobalTransform,
+        ),
+    >,
+    ancestor_query: Query<'w, 's, &'static ChildOf>,
+    world_query: Query<'w, 's, &'static GlobalTransform, Without<ComputedNode>>,
+    camera_query: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+    ui_scale: Res<'w, UiScale>,
+}
+
+impl<'w, 's> LinkRectParam<'w, 's> {
+    /// The rect of a node or a text span in the logical UI space of its camera.
+    pub(crate) fn logical_rect(&self, entity: Entity) -> Option<Rect> {
+        if let Ok((computed, transform)) = self.node_query.get(entity) {
+            let half_size = computed.size() / 2.0;
+            let rect = Rect::from_corners(
+                transform.transform_point2(-half_size),
+                transform.transform_point2(half_size),
+            );
+            return Some(scale_rect(rect, computed.inverse_scale_factor()));
+        }
+
+        // Text spans are not nodes, find them in the text block that contains them
+        for ancestor in self.ancestor_query.iter_ancestors(entity) {
+            let Ok((text_block, layout_info, computed, transform)) =
+                self.text_block_query.get(ancestor)
+            else {
+                continue;
+            };
+            let span_index = text_block
+                .entities()
+                .iter()
+                .position(|text_entity| text_entity.entity == entity)?;
+
+            let top_left = -computed.size() / 2.0;
+            let rect = layout_info
+                .run_geometry
+                .iter()
+                .filter(|run| run.span_index == span_index)
+                .map(|run| {
+                    Rect::from_corners(
+                        transform.transform_point2(run.bounds.min + top_left),
+                        transform.transform_point2(run.bounds.max + top_left),
+                    )
+                })
+                .reduce(|a, b| a.union(b))?;
+            return Some(scale_rect(rect, computed.inverse_scale_factor()));
+        }
+        None
+    }
+
+    /// The rect of a link in the logical UI space of `camera`.
+    /// Links in the world have no size and are the point they are projected to.
+    pub(crate) fn link_logical_rect(&self, entity: Entity, camera: Entity) -> Option<Rect> {
+        self.logical_rect(entity).or_else(|| {
+            let position = self.world_position(entity, camera)?;
+            Some(Rect::from_center_size(position, Vec2::ZERO))
+        })
+    }
+
+    /// Where an entity in the world is projected to in the logical UI space of `camera`.
+    /// None for UI nodes or if the entity is not in front of the camera.
+    pub(crate) fn world_position(&self, entity: Entity, camera: Entity) -> Option<Vec2> {
+        let transform = self.world_query.get(entity).ok()?;
+        let (camera, camera_transform) = self.camera_query.get(camera).ok()?;
+        let position = camera
+            .world_to_viewport(camera_transform, transform.translation())
+            .ok()?;
+        Some(position / self.ui_scale.0)
+    }
+}
+
+fn scale_rect(rect: Rect, scale: f32) -> Rect {
+    Rect {
+        min: rect.min * scale,
+        max: rect.max * scale,
+    }
+}
+
+/// Top left position for a tooltip of `size` next to `anchor`.
+/// Uses the first of `sides` that fits inside `viewport_size` falling back
+/// to the side with the most room.
+pub(crate) fn anchored_position(
+    anchor: Rect,
+    size: Vec2,
+    viewport_size: Vec2,
+    sides: &[TooltipSide],
+) -> Vec2 {
+    let room = |side: TooltipSide| match side {
+        TooltipSide::Above => anchor.min.y - TOOLTIP_OFFSET - size.y,
+        TooltipSide::Below => viewport_size.y - anchor.max.y - TOOLTIP_OFFSET - size.y,
+        TooltipSide::Left => anchor.min.x - TOOLTIP_OFFSET - size.x,
+        TooltipSide::Right => viewport_size.x - anchor.max.x - TOOLTIP_OFFSET - size.x,
+    };
+    let side = sides
+        .iter()
+        .copied()
+        .find(|side| room(*side) >= 0.0)
+        .or_else(|| {
+            sides
+                .iter()
+                .copied()
+                .max_by(|a, b| room(*a).total_cmp(&room(*b)))
+        })
+        .unwrap_or(TooltipSide::Below);
+
+    let centre = anchor.center() - size / 2.0;
+    let position = match side {
+        TooltipSide::Above => Vec2::new(centre.x, anchor.min.y - TOOLTIP_OFFSET - size.y),
+        TooltipSide::Below => Vec2::new(centre.x, anchor.max.y + TOOLTIP_OFFSET),
+        TooltipSide::Left => Vec2::new(anchor.min.x - TOOLTIP_OFFSET - size.x, centre.y),
+        TooltipSide::Right => Vec2::new(anchor.max.x + TOOLTIP_OFFSET, centre.y),
+    };
+    // Keep the tooltip on screen along the side it is placed
+    position.clamp(Vec2::ZERO, (viewport_size - size).max(Vec2::ZERO))
+}
+
+/// Top left position for a tooltip of `size` diagonal to the cursor.
+/// On each axis the side with the most room is preferred, flipping to the
+/// other side if the tooltip does not fit.
+pub(crate) fn cursor_position(cursor: Vec2, size: Vec2, viewport_size: Vec2) -> Vec2 {
+    let axis = |cursor: f32, size: f32, viewport_size: f32| {
+        let after = cursor + TOOLTIP_OFFSET;
+        let before = cursor - TOOLTIP_OFFSET - size;
+        let fits_after = after + size <= viewport_size;
+        let fits_before = before >= 0.0;
+        let position = if cursor > viewport_size / 2.0 {
+            if fits_before || !fits_after {
+                before
+            } else {
+                after
+            }
+        } else if fits_after || !fits_before {
+            after
+        } else {
+            before
+        };
+        position.clamp(0.0, (viewport_size - size).max(0.0))
+    };
+    Vec2::new(
+        axis(cursor.x, size.x, viewport_size.x),
+        axis(cursor.y, size.y, viewport_size.y),
+    )
+}
+
+/// Top left position for a tooltip of `size` beside the `parent` tooltip, lined up with the `link`
+/// it was spawned from.
+/// The first side where the tooltip does not overlap any of the `chain` of tooltips it is nested
+/// under is used, otherwise the side that overlaps them the least.
+pub(crate) fn cascade_position(
+    parent: Rect,
+    link: Rect,
+    chain: &[Rect],
+    size: Vec2,
+    viewport_size: Vec2,
+) -> Vec2 {
+    let max = (viewport_size - size).max(Vec2::ZERO);
+    let candidates = [
+        Vec2::new(parent.max.x + TOOLTIP_OFFSET, link.min.y),
+        Vec2::new(parent.min.x - TOOLTIP_OFFSET - size.x, link.min.y),
+        Vec2::new(link.min.x, parent.max.y + TOOLTIP_OFFSET),
+        Vec2::new(link.min.x, parent.min.y - TOOLTIP_OFFSET - size.y),
+    ]
+    .map(|position| position.clamp(Vec2::ZERO, max));
+
+    let overlap = |position: Vec2| {
+        let rect = Rect::from_corners(position, position + size);
+        chain
+            .iter()
+            .map(|other| {
+                let intersection = rect.intersect(*other);
+                if intersection.is_empty() {
+                    0.0
+                } else {
+                    intersection.width() * intersection.height()
+                }
+            })
+            .sum::<f32>()
+    };
+
+    candidates
+        .iter()
+        .copied()
+        .find(|position| overlap(*position) == 0.0)
+        .or_else(|| {
+            candidates
+                .iter()
+                .copied()
+                .min_by(|a, b| overlap(*a).total_cmp(&overlap(*b)))
+        })
+        .unwrap_or(candidates[0])
+}
+
+/// The side of `tooltip` facing `link` and how far along that side the centre of the link is.
+/// The side with the largest gap is used, none if they overlap.
+pub(crate) fn caret_placement(tooltip: Rect, link: Rect) -> Option<(TooltipSide, f32)> {
+    let (side, _) = [
+        (TooltipSide::Above, tooltip.min.y - link.max.y),
+        (TooltipSide::Below, link.min.y - tooltip.max.y),
+        (TooltipSide::Left, tooltip.min.x - link.max.x),
+        (TooltipSide::Right, link.min.x - tooltip.max.x),
+    ]
+    .into_iter()
+    .filter(|(_, gap)| *gap >= 0.0)
+    .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    let centre = link.center().clamp(tooltip.min, tooltip.max) - tooltip.min;
+    let offset = match side {
+        TooltipSide::Above | TooltipSide::Below => centre.x,
+        TooltipSide::Left | TooltipSide::Right => centre.y,
+    };
+    Some((side, offset))
+}
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct PlaceTooltipQuery {
+    entity: Entity,
+    tooltip: &'static Tooltip,
+    placement: &'static TooltipPlacement,
+    cursor: &'static TooltipCursorPosition,
+    nested_of: Option<&'static TooltipsNestedOf>,
+    dragged: Option<&'static TooltipDragged>,
+    children: Option<&'static Children>,
+    node: &'static mut Node,
+    computed: &'static ComputedNode,
+    target_info: &'static ComputedUiRenderTargetInfo,
+    target_camera: &'static UiTargetCamera,
+    visibility: &'static mut Visibility,
+    placing: Option<&'static mut TooltipPlacing>,
+}
+
+/// Moves tooltips to their final position now that their size is known, flipping and nudging
+/// them so they stay inside the viewport.
+/// This runs every frame so tooltips are moved whenever their content or the viewport changes size.
+/// The tooltip is shown once layout has used its first position, tooltips following something that
+/// moves every frame keep moving after they are shown.
+fn place_tooltips(
+    mut tooltip_query: Query<PlaceTooltipQuery>,
+    mut caret_query: Query<(&mut TooltipCaret, &mut Node, &mut Visibility), Without<Tooltip>>,
+    nested_of_query: Query<&TooltipsNestedOf>,
+    link_rect: LinkRectParam,
+    mut commands: Commands,
+) {
+    for mut tooltip_item in &mut tooltip_query {
+        let size = tooltip_item.computed.size() * tooltip_item.computed.inverse_scale_factor();
+        let viewport_size = tooltip_item.target_info.logical_size();
+        let camera = tooltip_item.target_camera.entity();
+        // Dragged tooltips stay where they were dropped
+        let position = if let Some(dragged) = tooltip_item.dragged {
+            Some(clamp_to_viewport(dragged.0, size, viewport_size))
+        } else {
+            match tooltip_item.placement {
+                TooltipPlacement::Cursor | TooltipPlacement::FollowCursor => {
+                    Some(cursor_position(tooltip_item.cursor.0, size, viewport_size))
+                }
+                // If the link is gone the tooltip stays where it is
+                TooltipPlacement::Anchored { sides } => tooltip_item
+                    .tooltip
+                    .link()
+                    .and_then(|link| link_rect.link_logical_rect(link, camera))
+                    .map(|anchor| anchored_position(anchor, size, viewport_size, sides)),
+                TooltipPlacement::Cascade => match tooltip_item.nested_of {
+                    None => Some(cursor_position(tooltip_item.cursor.0, size, viewport_size)),
+                    Some(nested_of) => {
+                        let chain: Vec<Rect> = nested_of_query
+                            .iter_ancestors(tooltip_item.entity)
+                            .filter_map(|ancestor| link_rect.logical_rect(ancestor))
+                            .collect();
+                        let link = tooltip_item
+                            .tooltip
+                            .link()
+                            .and_then(|link| link_rect.link_logical_rect(link, camera))
+                            .unwrap_or(Rect::from_center_size(tooltip_item.cursor.0, Vec2::ZERO));
+                        link_rect.logical_rect(nested_of.0).map(|parent| {
+                            cascade_position(parent, link, &chain, size, viewport_size)
+                        })
+                    }
+                },
+                TooltipPlacement::Tracking => match tooltip_item.tooltip.link() {
+                    Some(link) if link_rect.logical_rect(link).is_none() => {
+                        let viewport = Rect::from_corners(Vec2::ZERO, viewport_size);
+                        let Some(link_position) = link_rect
+                            .world_position(link, camera)
+                            .filter(|link_position| viewport.contains(*link_position))
+                        else {
+                            commands.trigger(CloseTooltip {
+                                entity: tooltip_item.entity,
+                            });
+                            continue;
+                        };
+                        Some(cursor_position(link_position, size, viewport_size))
+                    }
+                    _ => Some(cursor_position(tooltip_item.cursor.0, size, viewport_size)),
+                },
+            }
+        };
+
+        if let Some(position) = position
+            && let Some(children) = tooltip_item.children
+        {
+            let tooltip_rect = Rect::from_corners(position, position + size);
+            let link = tooltip_item
+                .tooltip
+                .link()
+                .and_then(|link| link_rect.link_logical_rect(link, camera))
+                .unwrap_or(Rect::from_center_size(tooltip_item.cursor.0, Vec2::ZERO));
+            let placement = caret_placement(tooltip_rect, link);
+            let mut carets = caret_query.iter_many_mut(children);
+            while let Some((mut caret, mut node, mut visibility)) = carets.fetch_next() {
+                place_caret(&mut caret, &mut node, &mut visibility, placement);
+            }
+        }
+
+        if let Some(position) = position {
+            set_node_position(&mut tooltip_item.node, position);
+        }
+        let Some(placing) = &mut tooltip_item.placing else {
+            continue;
+        };
+        // Without a size layout has not run yet, the position is only final once it has a size
+        if placing.placed || position.is_none() {
+            *tooltip_item.visibility = Visibility::Inherited;
+            c!(commands.get_entity(tooltip_item.entity)).remove::<TooltipPlacing>();
+        } else if size != Vec2::ZERO {
+            placing.placed = true;
+        }
+    }
+}
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct FollowCursorQuery {
+    tooltip: &'static Tooltip,
+    placement: &'static TooltipPlacement,
+    target_camera: &'static UiTargetCamera,
+    cursor: &'static mut TooltipCursorPosition,
+}
+
+/// Updates the cursor position of [`TooltipPlacement::FollowCursor`] tooltips when the pointer moves
+/// over the link that spawned them, [`place_tooltips`] then moves the tooltip.
+fn follow_cursor(
+    hover: On<Pointer<Move>>,
+    mut tooltip_query: Query<
+        FollowCursorQuery,
+        (Without<ToolTipDebounced>, Without<TooltipLocked>),
+    >,
+    camera_query: Query<&Camera>,
+    ui_scale: Res<UiScale>,
+) {
+    for mut tooltip_item in &mut tooltip_query {
+        if tooltip_item.tooltip.link() != Some(hover.entity)
+            || !matches!(tooltip_item.placement, TooltipPlacement::FollowCursor)
+        {
+            continue;
+        }
+        let camera = c!(camera_query.get(tooltip_item.target_camera.entity()));
+        let (cursor_position, _) = c!(ui_space_position(
+            &hover.pointer_location,
+            camera,
+            &ui_scale
+        ));
+        tooltip_item.cursor.0 = cursor_position;
+    }
+}
+
+#[derive(QueryData)]
+struct DragTooltipQuery {
+    computed: &'static ComputedNode,
+    target_info: &'static ComputedUiRenderTargetInfo,
+    dragged: Option<&'static TooltipDragged>,
+}
+
+/// Moves a locked tooltip when it is dragged by its [`TooltipTitleNode`] with the primary button,
+/// keeping it inside the viewport.
+fn drag_tooltip(
+    drag: On<Pointer<Drag>>,
+    title_query: Query<&ChildOf, With<TooltipTitleNode>>,
+    tooltip_query: Query<DragTooltipQuery, (With<Tooltip>, With<TooltipLocked>)>,
+    link_rect: LinkRectParam,
+    ui_scale: Res<UiScale>,
+    mut commands: Commands,
+) {
+    if drag.button != PointerButton::Primary {
+        return;
+    }
+    let tooltip = rq!(title_query.get(drag.entity)).parent();
+    let tooltip_item = rq!(tooltip_query.get(tooltip));
+    let current = match tooltip_item.dragged {
+        Some(dragged) => dragged.0,
+        None => r!(link_rect.logical_rect(tooltip)).min,
+    };
+    let size = tooltip_item.computed.size() * tooltip_item.computed.inverse_scale_factor();
+    let position = clamp_to_viewport(
+        current + drag.delta / ui_scale.0,
+        size,
+        tooltip_item.target_info.logical_size(),
+    );
+    r!(commands.get_entity(tooltip)).insert(TooltipDragged(position));
+    commands.trigger(TooltipMoved {
+        entity: tooltip,
+        position,
+    });
+}
+
+/// Sends [`TooltipDropped`] when dragging a tooltip by its title ends.
+fn drop_tooltip(
+    drag_end: On<Pointer<DragEnd>>,
+    title_query: Query<&ChildOf, With<TooltipTitleNode>>,
+    tooltip_query: Query<&TooltipDragged>,
+    mut commands: Commands,
+) {
+    let tooltip = rq!(title_query.get(drag_end.entity)).parent();
+    let dragged = rq!(tooltip_query.get(tooltip));
+    commands.trigger(TooltipDropped {
+        entity: tooltip,
+        position: dragged.0,
+    });
+}
+
+/// Moves `position` so a tooltip of `size` there is inside the viewport where possible.
+fn clamp_to_viewport(position: Vec2, size: Vec2, viewport_size: Vec2) -> Vec2 {
+    position.clamp(Vec2::ZERO, (viewport_size - size).max(Vec2::ZERO))
+}
+
+/// Moves the caret just outside the side of its tooltip facing the link, hiding it if there is none.
+fn place_caret(
+    caret: &mut Mut<TooltipCaret>,
+    node: &mut Mut<Node>,
+    visibility: &mut Mut<Visibility>,
+    placement: Option<(TooltipSide, f32)>,
+) {
+    let Some((side, offset)) = placement else {
+        caret.set_if_neq(TooltipCaret {
+            side: None,
+            offset: 0.0,
+        });
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    caret.set_if_neq(TooltipCaret {
+        side: Some(side),
+        offset,
+    });
+    visibility.set_if_neq(Visibility::Inherited);
+
+    let outside = Val::Percent(100.);
+    let along = Val::Px(offset);
+    let (left, right, top, bottom) = match side {
+        TooltipSide::Above => (along, Val::Auto, Val::Auto, outside),
+        TooltipSide::Below => (along, Val::Auto, outside, Val::Auto),
+        TooltipSide::Left => (Val::Auto, outside, along, Val::Auto),
+        TooltipSide::Right => (outside, Val::Auto, along, Val::Auto),
+    };
+    if node.left != left || node.right != right || node.top != top || node.bottom != bottom {
+        node.left = left;
+        node.right = right;
+        node.top = top;
+        node.bottom = bottom;
+    }
+}
+
+/// Sets the top left of the node, without triggering change detection if it is already there.
+pub(crate) fn set_node_position(node: &mut Mut<Node>, position: Vec2) {
+    let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+    if node.left == left && node.top == top && node.right == Val::Auto && node.bottom == Val::Auto {
+        return;
+    }
+    node.left = left;
+    node.top = top;
+    node.right = Val::Auto;
+    node.bottom = Val::Auto;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy_app::Propagate;
+    use bevy_camera::{ComputedCameraValues, RenderTargetInfo};
+    use bevy_ecs::{
+        schedule::Schedule,
+        system::RunSystemOnce,
+        world::{EntityWorldMut, World},
+    };
+    use bevy_math::{Mat4, UVec2, Vec3};
+    use bevy_ui::update::propagate_ui_target_cameras;
+
+    use super::*;
+
+    const VIEWPORT: Vec2 = Vec2::new(800., 600.);
+    const SIZE: Vec2 = Vec2::new(60., 40.);
+
+    fn sides() -> Vec<TooltipSide> {
+        vec![
+            TooltipSide::Below,
+            TooltipSide::Above,
+            TooltipSide::Right,
+            TooltipSide::Left,
+        ]
+    }
+
+    #[test]
+    fn anchored_uses_first_side_with_room() {
+        let anchor = Rect::new(100., 100., 140., 120.);
+        let position = anchored_position(anchor, SIZE, VIEWPORT, &sides());
+        assert_eq!(position, Vec2::new(90., 128.));
+    }
+
+    #[test]
+    fn anchored_flips_at_viewport_edge() {
+        let anchor = Rect::new(100., 570., 140., 590.);
+        let position = anchored_position(anchor, SIZE, VIEWPORT, &sides());
+        assert_eq!(position, Vec2::new(90., 522.));
+    }
+
+    #[test]
+    fn anchored_clamps_along_side() {
+        let anchor = Rect::new(0., 100., 20., 120.);
+        let position = anchored_position(anchor, SIZE, VIEWPORT, &sides());
+        assert_eq!(position, Vec2::new(0., 128.));
+    }
+
+    #[test]
+    fn anchored_without_room_uses_side_with_most_room() {
+        let anchor = Rect::new(100., 10., 140., 30.);
+        let sides = [TooltipSide::Above, TooltipSide::Right];
+        let position = anchored_position(anchor, SIZE, Vec2::new(200., 600.), &sides);
+        assert_eq!(position, Vec2::new(140., 0.));
+    }
+
+    #[test]
+    fn anchored_larger_than_viewport() {
+        let anchor = Rect::new(100., 100., 140., 120.);
+        let position = anchored_position(anchor, Vec2::new(1000., 700.), VIEWPORT, &sides());
+        assert_eq!(position, Vec2::ZERO);
+    }
+
+    #[test]
+    fn cursor_after_in_top_left() {
+        let position = cursor_position(Vec2::new(100., 100.), SIZE, VIEWPORT);
+        assert_eq!(position, Vec2::new(108., 108.));
+    }
+
+    #[test]
+    fn cursor_before_in_bottom_right() {
+        let position = cursor_position(Vec2::new(700., 500.), SIZE, VIEWPORT);
+        assert_eq!(position, Vec2::new(632., 452.));
+    }
+
+    #[test]
+    fn cursor_at_centre_goes_after() {
+        let position = cursor_position(VIEWPORT / 2., SIZE, VIEWPORT);
+        assert_eq!(position, Vec2::new(408., 308.));
+    }
+
+    #[test]
+    fn cursor_at_edge_is_clamped() {
+        let position = cursor_position(Vec2::new(795., 5.), SIZE, VIEWPORT);
+        assert_eq!(position, Vec2::new(727., 13.));
+    }
+
+    #[test]
+    fn cursor_larger_than_viewport() {
+        let position = cursor_position(Vec2::new(100., 100.), Vec2::new(1000., 700.), VIEWPORT);
+        assert_eq!(position, Vec2::ZERO);
+    }
+
+    #[test]
+    fn cascade_prefers_right() {
+        let parent = Rect::new(100., 100., 300., 300.);
+        let link = Rect::new(120., 150., 180., 170.);
+        let position = cascade_position(parent, link, &[parent], Vec2::new(100., 80.), VIEWPORT);
+        assert_eq!(position, Vec2::new(308., 150.));
+    }
+
+    #[test]
+    fn cascade_goes_left_at_viewport_edge() {
+        let parent = Rect::new(600., 100., 790., 300.);
+        let link = Rect::new(620., 150., 680., 170.);
+        let position = cascade_position(parent, link, &[parent], Vec2::new(100., 80.), VIEWPORT);
+        assert_eq!(position, Vec2::new(492., 150.));
+    }
+
+    #[test]
+    fn cascade_every_side_overlapping_uses_least_overlap() {
+        let viewport = Vec2::new(300., 300.);
+        let parent = Rect::new(0., 0., 300., 300.);
+        let grandparent = Rect::new(150., 0., 300., 300.);
+        let link = Rect::new(120., 150., 180., 170.);
+        let position = cascade_position(
+            parent,
+            link,
+            &[parent, grandparent],
+            Vec2::new(100., 80.),
+            viewport,
+        );
+        assert_eq!(position, Vec2::new(0., 150.));
+    }
+
+    #[test]
+    fn caret_faces_link() {
+        let tooltip = Rect::new(100., 100., 200., 150.);
+        let link = Rect::new(140., 180., 160., 190.);
+        assert_eq!(
+            caret_placement(tooltip, link),
+            Some((TooltipSide::Below, 50.))
+        );
+    }
+
+    #[test]
+    fn caret_uses_largest_gap_and_clamps_offset() {
+        let tooltip = Rect::new(100., 100., 200., 150.);
+        let link = Rect::new(300., 10., 310., 20.);
+        assert_eq!(
+            caret_placement(tooltip, link),
+            Some((TooltipSide::Right, 0.))
+        );
+    }
+
+    #[test]
+    fn caret_hidden_when_overlapping() {
+        let tooltip = Rect::new(100., 100., 200., 150.);
+        let link = Rect::new(120., 120., 130., 130.);
+        assert_eq!(caret_placement(tooltip, link), None);
+    }
+
+    /// A world with an 800 by 600 orthographic camera looking at the origin and a tooltip
+    /// opened by `link` already laid out with [`SIZE`].
+    fn placing_world(placement: TooltipPlacement) -> (World, Entity, Entity) {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        let camera = world
+            .spawn((
+                Camera {
+                    computed: ComputedCameraValues {
+                        clip_from_view: Mat4::orthographic_rh(
+                            -400., 400., -300., 300., -1000., 1000.,
+                        ),
+                        target_info: Some(RenderTargetInfo {
+                            physical_size: UVec2::new(800, 600),
+                            scale_factor: 1.,
+                        }),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                GlobalTransform::IDENTITY,
+            ))
+            .id();
+        let link = world.spawn(GlobalTransform::IDENTITY).id();
+        let tooltip = world
+            .spawn((
+                Tooltip {
+                    link: Some(link),
+                    opened: Duration::ZERO,
+                },
+                placement,
+                TooltipCursorPosition(Vec2::new(100., 100.)),
+                Node::default(),
+                ComputedNode {
+                    size: SIZE,
+                    inverse_scale_factor: 1.,
+                    ..Default::default()
+                },
+                UiTargetCamera(camera),
+                Visibility::Hidden,
+                TooltipPlacing::default(),
+            ))
+            .id();
+        world.run_system_once(propagate_ui_target_cameras).unwrap();
+        let target_info = world
+            .get::<Propagate<ComputedUiRenderTargetInfo>>(tooltip)
+            .unwrap()
+            .0;
+        world.entity_mut(tooltip).insert(target_info);
+        (world, tooltip, link)
+    }
+
+    /// Runs [`place_tooltips`] for `frames` frames, calling `update` before each one.
+    /// Returns the left of the tooltip after each frame.
+    fn place_frames(
+        world: &mut World,
+        tooltip: Entity,
+        frames: usize,
+        mut update: impl FnMut(&mut EntityWorldMut, usize),
+    ) -> Vec<Val> {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(place_tooltips);
+        (0..frames)
+            .map(|frame| {
+                update(&mut world.entity_mut(tooltip), frame);
+                schedule.run(world);
+                world.get::<Node>(tooltip).unwrap().left
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tracking_moving_link_is_shown() {
+        let (mut world, tooltip, link) = placing_world(TooltipPlacement::Tracking);
+        let lefts = place_frames(&mut world, tooltip, 4, |tooltip, frame| {
+            tooltip.world_scope(|world| {
+                *world.get_mut::<GlobalTransform>(link).unwrap() =
+                    GlobalTransform::from_translation(Vec3::new(frame as f32 * 10., 0., 0.));
+            });
+        });
+
+        assert_eq!(
+            world.get::<Visibility>(tooltip),
+            Some(&Visibility::Inherited)
+        );
+        assert!(!world.entity(tooltip).contains::<TooltipPlacing>());
+        assert!(lefts.windows(2).all(|lefts| lefts[0] != lefts[1]));
+    }
+
+    #[test]
+    fn follow_cursor_moving_pointer_is_shown() {
+        let (mut world, tooltip, _) = placing_world(TooltipPlacement::FollowCursor);
+        let lefts = place_frames(&mut world, tooltip, 4, |tooltip, frame| {
+            tooltip.insert(TooltipCursorPosition(Vec2::new(
+                100. + frame as f32 * 10.,
+                100.,
+            )));
+        });
+
+        assert_eq!(
+            world.get::<Visibility>(tooltip),
+            Some(&Visibility::Inherited)
+        );
+        assert!(lefts.windows(2).all(|lefts| lefts[0] != lefts[1]));
+    }
+
+    #[test]
+    fn hidden_until_laid_out() {
+        let (mut world, tooltip, _) = placing_world(TooltipPlacement::Cursor);
+        world.get_mut::<ComputedNode>(tooltip).unwrap().size = Vec2::ZERO;
+        place_frames(&mut world, tooltip, 3, |_, _| {});
+
+        assert_eq!(world.get::<Visibility>(tooltip), Some(&Visibility::Hidden));
+    }
+}
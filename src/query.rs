@@ -11,6 +11,7 @@ use tiny_bail::prelude::*;
 
 use crate::{
     layout::{TooltipStringText, TooltipTextNode, TooltipTitleNode, TooltipTitleText},
+    placement::TooltipCaret,
     prelude::TooltipHighlightLink,
     term::TooltipTermLinkRecursive,
 };
@@ -43,6 +44,10 @@ pub struct TooltipEntities {
     /// All entities that highlight panels
     /// That is [`TooltipHighlightLink`].
     pub highlight_texts: Vec<Entity>,
+
+    /// The entity pointing to the link that spawned the tooltip.
+    /// That is [`TooltipCaret`], only present when enabled in [`crate::TooltipConfiguration`].
+    pub caret: Option<Entity>,
 }
 
 #[derive(SystemParam)]
@@ -59,6 +64,7 @@ pub struct TooltipEntitiesParam<'w, 's> {
     string_texts_query: Query<'w, 's, Entity, With<TooltipStringText>>,
     links_query: Query<'w, 's, Entity, With<TooltipTermLinkRecursive>>,
     highlights_query: Query<'w, 's, Entity, With<TooltipHighlightLink>>,
+    caret_query: Query<'w, 's, Entity, With<TooltipCaret>>,
 }
 
 impl<'w, 's> TooltipEntitiesParam<'w, 's> {
@@ -110,6 +116,13 @@ impl<'w, 's> TooltipEntitiesParam<'w, 's> {
                 highlight_texts.push(highlight);
             }
         }
+        let mut caret = None;
+        for caret_entity in self.caret_query {
+            if entity == self.ancestor_query.root_ancestor(caret_entity) {
+                caret = Some(caret_entity);
+                break;
+            }
+        }
         Some(TooltipEntities {
             title_node: r!(title_node),
             title_text: r!(title_text),
@@ -117,6 +130,7 @@ impl<'w, 's> TooltipEntitiesParam<'w, 's> {
             string_texts,
             term_texts: link_texts,
             highlight_texts,
+            caret,
         })
     }
 }
@@ -1,18 +1,24 @@
 //! Terms is how tooltips find out what to display given a word to link.
 
+use std::time::Duration;
+
 use bevy_ecs::{
     component::Component,
     entity::Entity,
     observer::On,
-    system::{Commands, Res},
+    query::With,
+    resource::Resource,
+    system::{Commands, Query, Res, ResMut},
 };
-use bevy_picking::events::{Over, Pointer};
-use bevy_time::{Timer, TimerMode};
+use bevy_picking::events::{Move, Over, Pointer, Press, Release};
+use bevy_time::{Time, Timer, TimerMode};
 use tiny_bail::prelude::*;
 
-use crate::{ActivationMethod, TooltipConfiguration, TooltipLinkTimer};
+use crate::{ActivationMethod, Tooltip, TooltipConfiguration, TooltipDepth, TooltipLinkTimer};
 
 /// Place this on a node or text that you want to spawn a Tooltip.
+/// This can also be placed on pickable entities in the world such as sprites and meshes,
+/// their tooltip is opened where the entity is on screen.
 /// The tooltip displayed will be the contents of [`crate::TooltipMap`].
 #[derive(Debug, Component)]
 pub struct TooltipTermLink {
@@ -34,7 +40,7 @@ impl TooltipTermLink {
 
 /// This is used for putting links of tooltips in tooltips
 /// Should not be created by end users but can safely read if you are interested in recursive case
-/// Recursive case may be treated seperately in future such as shorter hover times.
+/// Hover times of recursive links can be set separately in [`crate::TooltipConfiguration`].
 #[derive(Debug, Component)]
 pub struct TooltipTermLinkRecursive {
     pub(crate) parent_entity: Entity,
@@ -61,19 +67,98 @@ impl TooltipTermLinkRecursive {
 }
 
 /// This triggers for [`crate::Tooltip`] links
-/// If configured to display on hover this will add a [`crate::TooltipLinkTimer`] that unless pointer moves
+/// If configured to display on hover, or hover with a modifier key, this will add a [`crate::TooltipLinkTimer`] that unless pointer moves
 /// away from will spawn a [`crate::Tooltip`].
+/// Links inside tooltips use the nested hover time for the depth of the tooltip they would spawn.
+/// Links outside tooltips spawn without waiting if a tooltip was open within the skip delay.
 pub(crate) fn hover_time_spawn(
     hover: On<Pointer<Over>>,
+    recursive_query: Query<&TooltipTermLinkRecursive>,
+    depth_query: Query<&TooltipDepth>,
+    warm_up: Res<HoverWarmUp>,
+    time_res: Res<Time>,
     tooltip_configuration: Res<TooltipConfiguration>,
     mut commands: Commands,
 ) {
     let current_activation = tooltip_configuration.activation_method.clone();
-    if let ActivationMethod::Hover { time } = current_activation {
+    if let ActivationMethod::Hover { time } | ActivationMethod::Modifier { time, .. } =
+        current_activation
+    {
+        let depth = match recursive_query.get(hover.entity) {
+            Ok(link) => depth_query
+                .get(link.parent_entity)
+                .map_or(1, |depth| depth.depth() + 1),
+            Err(_) => 0,
+        };
+        let warm = depth == 0
+            && tooltip_configuration
+                .hover_skip_delay
+                .zip(warm_up.last_open)
+                .is_some_and(|(skip_delay, last_open)| {
+                    time_res.elapsed().saturating_sub(last_open) <= skip_delay
+                });
+        let time = if warm {
+            Duration::ZERO
+        } else {
+            tooltip_configuration.nested_hover_time.time(time, depth)
+        };
         {
             r!(commands.get_entity(hover.entity)).insert(TooltipLinkTimer {
                 timer: Timer::new(time, TimerMode::Once),
+                pointer_id: hover.pointer_id,
+                camera: hover.hit.camera,
+                moved: 0.0,
             });
         }
     }
 }
+
+/// Records how far the pointer moved over a link that is waiting to spawn a [`crate::Tooltip`],
+/// used to find the pointers speed for hover intent.
+pub(crate) fn hover_intent_move(
+    hover: On<Pointer<Move>>,
+    mut timer_query: Query<&mut TooltipLinkTimer>,
+) {
+    let mut link_timer = rq!(timer_query.get_mut(hover.entity));
+    link_timer.moved += hover.delta.length();
+}
+
+/// This triggers for [`crate::Tooltip`] links
+/// If configured to display on long press this will add a [`crate::TooltipLinkTimer`] that unless
+/// the press is released will spawn a [`crate::Tooltip`].
+pub(crate) fn long_press_spawn(
+    press: On<Pointer<Press>>,
+    tooltip_configuration: Res<TooltipConfiguration>,
+    mut commands: Commands,
+) {
+    if let ActivationMethod::LongPress { time } = tooltip_configuration.activation_method {
+        r!(commands.get_entity(press.entity)).insert(TooltipLinkTimer {
+            timer: Timer::new(time, TimerMode::Once),
+            pointer_id: press.pointer_id,
+            camera: press.hit.camera,
+            moved: 0.0,
+        });
+    }
+}
+
+/// Removes long press timer when the press is released before it finished.
+pub(crate) fn long_press_cancel(release: On<Pointer<Release>>, mut commands: Commands) {
+    r!(commands.get_entity(release.entity)).remove::<TooltipLinkTimer>();
+}
+
+/// When a [`crate::Tooltip`] was last open, so hovering links soon after skips the hover time.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct HoverWarmUp {
+    last_open: Option<Duration>,
+}
+
+/// Records the time while any [`crate::Tooltip`] is open.
+pub(crate) fn track_warm_up(
+    tooltip_query: Query<(), With<Tooltip>>,
+    time_res: Res<Time>,
+    mut warm_up: ResMut<HoverWarmUp>,
+) {
+    if !tooltip_query.is_empty() {
+        warm_up.last_open = Some(time_res.elapsed());
+    }
+}